@@ -0,0 +1,58 @@
+use std::thread;
+
+/// Lines handed to each worker per round in batch mode.
+pub const CHUNK: usize = 1024;
+
+/// Applies `f` to every line on up to `jobs` threads and returns the results
+/// in input order. Each worker takes one contiguous slice of `lines`.
+pub fn process<T, F>(lines: &[String], jobs: usize, f: F) -> Vec<T>
+where
+    T: Send,
+    F: Fn(&str) -> T + Sync,
+{
+    if jobs <= 1 || lines.len() <= 1 {
+        return lines.iter().map(|line| f(line)).collect();
+    }
+
+    let size = lines.len().div_ceil(jobs);
+    let f = &f;
+
+    thread::scope(|scope| {
+        let workers = lines
+            .chunks(size)
+            .map(|chunk| scope.spawn(move || chunk.iter().map(|line| f(line)).collect::<Vec<_>>()))
+            .collect::<Vec<_>>();
+
+        workers
+            .into_iter()
+            .flat_map(|worker| worker.join().unwrap())
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::batch::process;
+
+    #[test]
+    fn test_process_preserves_order() {
+        let lines = (0..100).map(|i| i.to_string()).collect::<Vec<_>>();
+
+        for jobs in [1, 2, 3, 8, 200] {
+            let results = process(&lines, jobs, |line| format!("<{}>", line));
+
+            assert_eq!(
+                results,
+                (0..100).map(|i| format!("<{}>", i)).collect::<Vec<_>>()
+            );
+        }
+    }
+
+    #[test]
+    fn test_process_empty() {
+        assert_eq!(
+            process(&[], 4, |line| line.to_string()),
+            Vec::<String>::new()
+        );
+    }
+}
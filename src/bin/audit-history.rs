@@ -0,0 +1,74 @@
+//! Re-ranks PokerStars hand histories and reports every hand whose declared
+//! winners or awarded amounts disagree with the evaluator:
+//! `cargo run --bin audit-history -- HISTORY...` (stdin without arguments).
+
+use std::io;
+use std::io::Read;
+use std::process;
+
+use poker::history::audit::audit;
+use poker::history::pokerstars::parse_all;
+
+/// The exit code for unreadable input, as for `poker`.
+const EXIT_IO: i32 = 3;
+
+fn main() {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+
+    let texts = match read(&args) {
+        Ok(texts) => texts,
+        Err(error) => {
+            eprintln!("audit-history: {}", error);
+            process::exit(EXIT_IO);
+        }
+    };
+
+    let mut audited = 0;
+    let mut disagreeing = 0;
+    let mut skipped = 0;
+
+    for hand in texts.iter().flat_map(|text| parse_all(text)) {
+        let (id, findings) = match hand.and_then(|hand| audit(&hand).map(|f| (hand.id, f))) {
+            Ok(audited) => audited,
+            Err(error) => {
+                eprintln!("skipped: {}", error);
+                skipped += 1;
+                continue;
+            }
+        };
+
+        audited += 1;
+        if !findings.is_empty() {
+            disagreeing += 1;
+        }
+        for finding in findings {
+            println!("{}: {}", id, finding);
+        }
+    }
+
+    eprintln!(
+        "{} hands audited, {} disagree, {} skipped",
+        audited, disagreeing, skipped
+    );
+
+    if disagreeing > 0 {
+        process::exit(1);
+    }
+}
+
+/// The files named in `args`, or stdin without any.
+fn read(args: &[String]) -> io::Result<Vec<String>> {
+    if args.is_empty() {
+        let mut text = String::new();
+        io::stdin().read_to_string(&mut text)?;
+
+        return Ok(vec![text]);
+    }
+
+    args.iter()
+        .map(|path| {
+            std::fs::read_to_string(path)
+                .map_err(|error| io::Error::new(error.kind(), format!("{}: {}", path, error)))
+        })
+        .collect()
+}
@@ -0,0 +1,40 @@
+//! Regenerates the embedded preflop equity table:
+//! `cargo run --release --bin preflop-equity > src/preflop/equity.bin`
+
+use std::io::Write;
+use std::process;
+
+use poker::preflop::EquityTable;
+
+const DEFAULT_BOARDS: u32 = 200_000;
+const DEFAULT_SEED: u64 = 0;
+
+const USAGE: &str = "usage: preflop-equity [BOARDS [SEED]]";
+/// The exit code for bad arguments, as for `poker`.
+const EXIT_USAGE: i32 = 2;
+
+fn main() {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+
+    if args.len() > 2 {
+        usage(&format!("unexpected argument: {}", args[2]));
+    }
+
+    let boards = args.first().map_or(DEFAULT_BOARDS, |arg| {
+        arg.parse()
+            .unwrap_or_else(|_| usage(&format!("invalid number of boards: {}", arg)))
+    });
+    let seed = args.get(1).map_or(DEFAULT_SEED, |arg| {
+        arg.parse()
+            .unwrap_or_else(|_| usage(&format!("invalid seed: {}", arg)))
+    });
+
+    let table = EquityTable::generate(boards, seed);
+
+    std::io::stdout().write_all(&table.to_bytes()).unwrap();
+}
+
+fn usage(error: &str) -> ! {
+    eprintln!("{}\n\n{}", error, USAGE);
+    process::exit(EXIT_USAGE);
+}
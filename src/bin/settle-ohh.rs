@@ -0,0 +1,53 @@
+//! Re-ranks Open Hand History files and writes them back out with the pots
+//! awarded by the evaluator: `cargo run --bin settle-ohh -- HISTORY...`
+//! (stdin without arguments).
+
+use std::io;
+use std::io::Read;
+use std::process;
+
+use poker::history::audit::settle;
+use poker::history::ohh::parse_all;
+use poker::history::ohh::write;
+
+/// The exit code for unreadable input, as for `poker`.
+const EXIT_IO: i32 = 3;
+
+fn main() {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+
+    let texts = match read(&args) {
+        Ok(texts) => texts,
+        Err(error) => {
+            eprintln!("settle-ohh: {}", error);
+            process::exit(EXIT_IO);
+        }
+    };
+
+    for hand in texts.iter().flat_map(|text| parse_all(text)) {
+        match hand
+            .and_then(|hand| settle(&hand))
+            .and_then(|hand| write(&hand))
+        {
+            Ok(text) => println!("{}\n", text),
+            Err(error) => eprintln!("skipped: {}", error),
+        }
+    }
+}
+
+/// The files named in `args`, or stdin without any.
+fn read(args: &[String]) -> io::Result<Vec<String>> {
+    if args.is_empty() {
+        let mut text = String::new();
+        io::stdin().read_to_string(&mut text)?;
+
+        return Ok(vec![text]);
+    }
+
+    args.iter()
+        .map(|path| {
+            std::fs::read_to_string(path)
+                .map_err(|error| io::Error::new(error.kind(), format!("{}: {}", path, error)))
+        })
+        .collect()
+}
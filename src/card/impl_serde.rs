@@ -0,0 +1,82 @@
+use ::serde::de::Error;
+use ::serde::Deserialize;
+use ::serde::Deserializer;
+use ::serde::Serialize;
+use ::serde::Serializer;
+
+use crate::card::notation;
+use crate::card::Card;
+use crate::card::Rank;
+use crate::card::Suit;
+
+impl Serialize for Rank {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Rank {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let raw = String::deserialize(deserializer)?;
+
+        notation::rank(&raw).ok_or_else(|| D::Error::custom(format!("unknown rank value: {}", raw)))
+    }
+}
+
+impl Serialize for Suit {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Suit {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let raw = String::deserialize(deserializer)?;
+
+        notation::suit(&raw).ok_or_else(|| D::Error::custom(format!("unknown suit value: {}", raw)))
+    }
+}
+
+impl Serialize for Card {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Card {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let raw = String::deserialize(deserializer)?;
+
+        notation::card(&raw).ok_or_else(|| D::Error::custom(format!("unknown card value: {}", raw)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::card::Card;
+    use crate::card::Rank;
+    use crate::card::Suit;
+
+    #[test]
+    fn test_card() {
+        let card = Card(Rank::Ace, Suit::Diamonds);
+
+        assert_eq!(serde_json::to_string(&card).unwrap(), "\"Ad\"");
+        assert_eq!(serde_json::from_str::<Card>("\"Ad\"").unwrap(), card);
+        assert!(serde_json::from_str::<Card>("\"Ax\"").is_err());
+        assert!(serde_json::from_str::<Card>("\"Add\"").is_err());
+        assert_eq!(serde_json::from_str::<Card>("\"A♦\"").unwrap(), card);
+    }
+
+    #[test]
+    fn test_rank_and_suit() {
+        assert_eq!(serde_json::to_string(&Rank::Ten).unwrap(), "\"T\"");
+        assert_eq!(serde_json::from_str::<Rank>("\"T\"").unwrap(), Rank::Ten);
+        assert!(serde_json::from_str::<Rank>("\"1\"").is_err());
+
+        assert_eq!(serde_json::to_string(&Suit::Spades).unwrap(), "\"s\"");
+        assert_eq!(serde_json::from_str::<Suit>("\"s\"").unwrap(), Suit::Spades);
+        assert_eq!(serde_json::from_str::<Suit>("\"♠\"").unwrap(), Suit::Spades);
+        assert!(serde_json::from_str::<Suit>("\"x\"").is_err());
+    }
+}
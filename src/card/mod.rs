@@ -1,41 +1,48 @@
+#[cfg(feature = "serde")]
+mod impl_serde;
+pub mod notation;
+pub mod permutation;
 pub mod rank;
 pub use rank::Rank;
 pub mod suit;
 pub use suit::Suit;
+pub mod wild;
 
+use std::fmt;
 use std::hash::Hash;
-use std::str::FromStr;
+use std::io;
 
-#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Card(pub Rank, pub Suit);
 
-impl ToString for Card {
-    fn to_string(&self) -> String {
-        format!("{}{}", self.0.to_string(), self.1.to_string())
+impl fmt::Display for Card {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}{}", self.0, self.1)
     }
 }
 
+pub fn all_cards() -> Vec<Card> {
+    Rank::ALL
+        .iter()
+        .flat_map(|rank| Suit::ALL.iter().map(move |suit| Card(*rank, *suit)))
+        .collect()
+}
+
 pub fn parse_cards(s: &str) -> Vec<Card> {
-    let (ranks, suits): (Vec<_>, Vec<_>) = s
-        .chars()
-        .into_iter()
-        .enumerate()
-        .partition(|(i, _elem)| i % 2 == 0);
+    match notation::cards(s) {
+        Some(cards) => cards,
+        None => panic!("invalid cards: {}", s),
+    }
+}
 
-    ranks
-        .into_iter()
-        .zip(suits.into_iter())
-        .map(|((_, rank), (_, suit))| {
-            Card(
-                Rank::from_str(&rank.to_string()).unwrap(),
-                Suit::from_str(&suit.to_string()).unwrap(),
-            )
-        })
-        .collect::<Vec<_>>()
+fn invalid(reason: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, reason)
 }
 
 #[cfg(test)]
 mod tests {
+    use ::itertools::Itertools;
+
     use crate::card::Card;
     use crate::card::Rank;
     use crate::card::Suit;
@@ -61,6 +68,14 @@ mod tests {
             .all(|card| card == Card(Rank::Ace, Suit::Diamonds)));
     }
 
+    #[test]
+    fn test_all_cards() {
+        let cards = super::all_cards();
+
+        assert_eq!(cards.len(), 52);
+        assert_eq!(cards.iter().unique().count(), 52);
+    }
+
     #[test]
     fn test_to_string() {
         assert_eq!(
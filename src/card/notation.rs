@@ -0,0 +1,184 @@
+//! Card notations pasted from other clients (`10♠`, `ah`, `KD`) and the
+//! styles cards can be printed in.
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::card::Card;
+use crate::card::Rank;
+use crate::card::Suit;
+
+pub fn rank(raw: &str) -> Option<Rank> {
+    match raw {
+        "2" => Some(Rank::Two),
+        "3" => Some(Rank::Three),
+        "4" => Some(Rank::Four),
+        "5" => Some(Rank::Five),
+        "6" => Some(Rank::Six),
+        "7" => Some(Rank::Seven),
+        "8" => Some(Rank::Eight),
+        "9" => Some(Rank::Nine),
+        "T" | "t" | "10" => Some(Rank::Ten),
+        "J" | "j" => Some(Rank::Jack),
+        "Q" | "q" => Some(Rank::Queen),
+        "K" | "k" => Some(Rank::King),
+        "A" | "a" => Some(Rank::Ace),
+        _ => None,
+    }
+}
+
+pub fn suit(raw: &str) -> Option<Suit> {
+    match raw {
+        "c" | "C" | "♣" | "♧" => Some(Suit::Clubs),
+        "d" | "D" | "♦" | "♢" => Some(Suit::Diamonds),
+        "h" | "H" | "♥" | "♡" => Some(Suit::Hearts),
+        "s" | "S" | "♠" | "♤" => Some(Suit::Spades),
+        _ => None,
+    }
+}
+
+pub fn card(raw: &str) -> Option<Card> {
+    match cards(raw)?.as_slice() {
+        [card] => Some(*card),
+        _ => None,
+    }
+}
+
+/// Splits a run of cards such as `AhKd`, `10♠9♠` or `ah kd`; whitespace
+/// and commas between cards are ignored.
+pub fn cards(raw: &str) -> Option<Vec<Card>> {
+    let chars = raw
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != ',')
+        .collect::<Vec<_>>();
+
+    let mut cards = vec![];
+    let mut i = 0;
+
+    while i < chars.len() {
+        let width = if chars[i] == '1' && chars.get(i + 1) == Some(&'0') {
+            2
+        } else {
+            1
+        };
+
+        let rank = rank(&chars[i..i + width].iter().collect::<String>())?;
+        let suit = suit(&chars.get(i + width)?.to_string())?;
+
+        cards.push(Card(rank, suit));
+        i += width + 1;
+    }
+
+    Some(cards)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Style {
+    /// `Ah`, the notation every parser accepts.
+    Ascii,
+    /// `A♥`.
+    Unicode,
+    /// `A♥` coloured with ANSI escapes as in a four-colour deck: spades
+    /// in the default colour, hearts red, diamonds blue, clubs green.
+    FourColor,
+}
+
+impl FromStr for Style {
+    type Err = std::io::Error;
+
+    fn from_str(style: &str) -> Result<Self, Self::Err> {
+        match style {
+            "ascii" => Ok(Self::Ascii),
+            "unicode" => Ok(Self::Unicode),
+            "four-color" => Ok(Self::FourColor),
+            _ => Err(super::invalid(format!("unknown card style: {}", style))),
+        }
+    }
+}
+
+pub struct Styled(pub Card, pub Style);
+
+impl fmt::Display for Styled {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let Styled(Card(rank, suit), style) = self;
+
+        let symbol = match suit {
+            Suit::Clubs => "♣",
+            Suit::Diamonds => "♦",
+            Suit::Hearts => "♥",
+            Suit::Spades => "♠",
+        };
+
+        match style {
+            Style::Ascii => write!(f, "{}{}", rank, suit),
+            Style::Unicode => write!(f, "{}{}", rank, symbol),
+            Style::FourColor => {
+                let color = match suit {
+                    Suit::Clubs => "32",
+                    Suit::Diamonds => "34",
+                    Suit::Hearts => "31",
+                    Suit::Spades => "39",
+                };
+
+                write!(f, "\x1b[{}m{}{}\x1b[0m", color, rank, symbol)
+            }
+        }
+    }
+}
+
+impl Card {
+    pub fn styled(self, style: Style) -> Styled {
+        Styled(self, style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::card::notation::card;
+    use crate::card::notation::cards;
+    use crate::card::notation::Style;
+    use crate::card::parse_cards;
+    use crate::card::Card;
+    use crate::card::Rank;
+    use crate::card::Suit;
+
+    #[test]
+    fn test_cards() {
+        let expected = parse_cards("AhTsKd");
+
+        assert_eq!(cards("AhTsKd").unwrap(), expected);
+        assert_eq!(cards("A♥10♠K♦").unwrap(), expected);
+        assert_eq!(cards("ah ts kd").unwrap(), expected);
+        assert_eq!(cards("AH, 10S, KD").unwrap(), expected);
+        assert_eq!(cards("A♡T♤K♢").unwrap(), expected);
+        assert_eq!(cards("").unwrap(), vec![]);
+    }
+
+    #[test]
+    fn test_cards_invalid() {
+        assert_eq!(cards("Ah1s"), None);
+        assert_eq!(cards("AhK"), None);
+        assert_eq!(cards("Ax"), None);
+        assert_eq!(cards("11h"), None);
+        assert_eq!(card("AhKd"), None);
+        assert_eq!(card("10c"), Some(Card(Rank::Ten, Suit::Clubs)));
+    }
+
+    #[test]
+    fn test_styled() {
+        let card = Card(Rank::Ten, Suit::Hearts);
+
+        assert_eq!(card.styled(Style::Ascii).to_string(), "Th");
+        assert_eq!(card.styled(Style::Unicode).to_string(), "T♥");
+        assert_eq!(
+            card.styled(Style::FourColor).to_string(),
+            "\x1b[31mT♥\x1b[0m"
+        );
+        assert_eq!(Style::from_str("four-color").unwrap(), Style::FourColor);
+
+        let error = Style::from_str("emoji").unwrap_err();
+        assert_eq!(error.to_string(), "unknown card style: emoji");
+    }
+}
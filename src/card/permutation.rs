@@ -0,0 +1,94 @@
+use crate::card::Card;
+use crate::card::Suit;
+
+/// A relabelling of suits, indexed by the original suit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Permutation([Suit; 4]);
+
+impl Permutation {
+    pub fn identity() -> Self {
+        Self(Suit::ALL)
+    }
+
+    pub fn all() -> Vec<Self> {
+        let mut permutations = vec![];
+
+        for a in Suit::ALL.iter() {
+            for b in Suit::ALL.iter().filter(|s| *s != a) {
+                for c in Suit::ALL.iter().filter(|s| *s != a && *s != b) {
+                    for d in Suit::ALL.iter().filter(|s| *s != a && *s != b && *s != c) {
+                        permutations.push(Self([*a, *b, *c, *d]));
+                    }
+                }
+            }
+        }
+
+        permutations
+    }
+
+    pub fn suit(&self, suit: Suit) -> Suit {
+        self.0[suit as usize]
+    }
+
+    pub fn card(&self, card: Card) -> Card {
+        Card(card.0, self.suit(card.1))
+    }
+
+    pub fn cards(&self, cards: &[Card]) -> Vec<Card> {
+        cards.iter().map(|card| self.card(*card)).collect()
+    }
+
+    pub fn inverse(&self) -> Self {
+        let mut suits = Suit::ALL;
+
+        for suit in Suit::ALL.iter() {
+            suits[self.suit(*suit) as usize] = *suit;
+        }
+
+        Self(suits)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::itertools::Itertools;
+
+    use crate::card::permutation::Permutation;
+    use crate::card::Card;
+    use crate::card::Rank;
+    use crate::card::Suit;
+
+    #[test]
+    fn test_all() {
+        let permutations = Permutation::all();
+
+        assert_eq!(permutations.len(), 24);
+        assert_eq!(permutations.iter().unique().count(), 24);
+        assert_eq!(permutations[0], Permutation::identity());
+    }
+
+    #[test]
+    fn test_inverse() {
+        for permutation in Permutation::all() {
+            let inverse = permutation.inverse();
+
+            for suit in Suit::ALL.iter() {
+                assert_eq!(inverse.suit(permutation.suit(*suit)), *suit);
+            }
+        }
+    }
+
+    #[test]
+    fn test_card() {
+        let permutation = Permutation([Suit::Spades, Suit::Hearts, Suit::Clubs, Suit::Diamonds]);
+
+        assert_eq!(
+            permutation.card(Card(Rank::Ace, Suit::Diamonds)),
+            Card(Rank::Ace, Suit::Spades)
+        );
+        assert_eq!(
+            permutation.card(Card(Rank::Ace, Suit::Hearts)),
+            Card(Rank::Ace, Suit::Clubs)
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use std::fmt;
 use std::hash::Hash;
 use std::str::FromStr;
 
@@ -18,46 +19,54 @@ pub enum Rank {
     Ace,
 }
 
+impl Rank {
+    pub const ALL: [Rank; 13] = [
+        Self::Two,
+        Self::Three,
+        Self::Four,
+        Self::Five,
+        Self::Six,
+        Self::Seven,
+        Self::Eight,
+        Self::Nine,
+        Self::Ten,
+        Self::Jack,
+        Self::Queen,
+        Self::King,
+        Self::Ace,
+    ];
+}
+
 impl FromStr for Rank {
     type Err = std::io::Error;
 
     fn from_str(rank: &str) -> Result<Self, Self::Err> {
-        match rank {
-            "2" => Ok(Self::Two),
-            "3" => Ok(Self::Three),
-            "4" => Ok(Self::Four),
-            "5" => Ok(Self::Five),
-            "6" => Ok(Self::Six),
-            "7" => Ok(Self::Seven),
-            "8" => Ok(Self::Eight),
-            "9" => Ok(Self::Nine),
-            "T" => Ok(Self::Ten),
-            "J" => Ok(Self::Jack),
-            "Q" => Ok(Self::Queen),
-            "K" => Ok(Self::King),
-            "A" => Ok(Self::Ace),
-            _ => panic!("unknown rank value: {}", rank),
+        match super::notation::rank(rank) {
+            Some(rank) => Ok(rank),
+            None => Err(super::invalid(format!("unknown rank value: {}", rank))),
         }
     }
 }
 
-impl ToString for Rank {
-    fn to_string(&self) -> String {
-        match self {
-            Self::Two => "2".to_string(),
-            Self::Three => "3".to_string(),
-            Self::Four => "4".to_string(),
-            Self::Five => "5".to_string(),
-            Self::Six => "6".to_string(),
-            Self::Seven => "7".to_string(),
-            Self::Eight => "8".to_string(),
-            Self::Nine => "9".to_string(),
-            Self::Ten => "T".to_string(),
-            Self::Jack => "J".to_string(),
-            Self::Queen => "Q".to_string(),
-            Self::King => "K".to_string(),
-            Self::Ace => "A".to_string(),
-        }
+impl fmt::Display for Rank {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
+            Self::Two => "2",
+            Self::Three => "3",
+            Self::Four => "4",
+            Self::Five => "5",
+            Self::Six => "6",
+            Self::Seven => "7",
+            Self::Eight => "8",
+            Self::Nine => "9",
+            Self::Ten => "T",
+            Self::Jack => "J",
+            Self::Queen => "Q",
+            Self::King => "K",
+            Self::Ace => "A",
+        };
+
+        write!(f, "{}", s)
     }
 }
 
@@ -331,6 +340,9 @@ mod tests {
         assert_eq!(Rank::from_str("Q").unwrap(), Rank::Queen);
         assert_eq!(Rank::from_str("K").unwrap(), Rank::King);
         assert_eq!(Rank::from_str("A").unwrap(), Rank::Ace);
+
+        let error = Rank::from_str("1").unwrap_err();
+        assert_eq!(error.to_string(), "unknown rank value: 1");
     }
 
     #[test]
@@ -1,7 +1,8 @@
+use std::fmt;
 use std::hash::Hash;
 use std::str::FromStr;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Suit {
     Diamonds,
     Clubs,
@@ -9,28 +10,31 @@ pub enum Suit {
     Spades,
 }
 
+impl Suit {
+    pub const ALL: [Suit; 4] = [Self::Diamonds, Self::Clubs, Self::Hearts, Self::Spades];
+}
+
 impl FromStr for Suit {
     type Err = std::io::Error;
 
     fn from_str(suit: &str) -> Result<Self, Self::Err> {
-        match suit {
-            "c" => Ok(Self::Clubs),
-            "d" => Ok(Self::Diamonds),
-            "h" => Ok(Self::Hearts),
-            "s" => Ok(Self::Spades),
-            _ => panic!("unknown suit value: {}", suit),
+        match super::notation::suit(suit) {
+            Some(suit) => Ok(suit),
+            None => Err(super::invalid(format!("unknown suit value: {}", suit))),
         }
     }
 }
 
-impl ToString for Suit {
-    fn to_string(&self) -> String {
-        match self {
-            Self::Clubs => "c".to_string(),
-            Self::Diamonds => "d".to_string(),
-            Self::Hearts => "h".to_string(),
-            Self::Spades => "s".to_string(),
-        }
+impl fmt::Display for Suit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
+            Self::Clubs => "c",
+            Self::Diamonds => "d",
+            Self::Hearts => "h",
+            Self::Spades => "s",
+        };
+
+        write!(f, "{}", s)
     }
 }
 
@@ -54,6 +58,9 @@ mod tests {
         assert_eq!(Suit::from_str("d").unwrap(), Suit::Diamonds);
         assert_eq!(Suit::from_str("h").unwrap(), Suit::Hearts);
         assert_eq!(Suit::from_str("s").unwrap(), Suit::Spades);
+
+        let error = Suit::from_str("x").unwrap_err();
+        assert_eq!(error.to_string(), "unknown suit value: x");
     }
 
     #[test]
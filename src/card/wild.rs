@@ -0,0 +1,138 @@
+//! Jokers and wild ranks for home games and video poker. Jokers are always
+//! wild; which ranks are wild too, deuces for example, is up to the game.
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::card::notation;
+use crate::card::Card;
+use crate::card::Rank;
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum WildCard {
+    Natural(Card),
+    Joker,
+}
+
+impl WildCard {
+    pub fn is_wild(self, ranks: &[Rank]) -> bool {
+        match self {
+            Self::Natural(Card(rank, _)) => ranks.contains(&rank),
+            Self::Joker => true,
+        }
+    }
+}
+
+impl From<Card> for WildCard {
+    fn from(card: Card) -> Self {
+        Self::Natural(card)
+    }
+}
+
+impl FromStr for WildCard {
+    type Err = std::io::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match cards(s).as_deref() {
+            Some([card]) => Ok(*card),
+            _ => Err(super::invalid(format!("unknown card value: {}", s))),
+        }
+    }
+}
+
+impl fmt::Display for WildCard {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Natural(card) => write!(f, "{}", card),
+            Self::Joker => write!(f, "Jk"),
+        }
+    }
+}
+
+/// Like `notation::cards`, with jokers written `Jk` or `🃏`.
+pub fn cards(raw: &str) -> Option<Vec<WildCard>> {
+    let chars = raw
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != ',')
+        .collect::<Vec<_>>();
+
+    let mut cards = vec![];
+    let mut naturals = String::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let width = match (chars[i], chars.get(i + 1)) {
+            ('🃏', _) => 1,
+            ('J' | 'j', Some('k' | 'K')) => 2,
+            (c, _) => {
+                naturals.push(c);
+                i += 1;
+                continue;
+            }
+        };
+
+        cards.extend(notation::cards(&naturals)?.into_iter().map(WildCard::from));
+        cards.push(WildCard::Joker);
+        naturals.clear();
+        i += width;
+    }
+
+    cards.extend(notation::cards(&naturals)?.into_iter().map(WildCard::from));
+
+    Some(cards)
+}
+
+pub fn parse_wild_cards(s: &str) -> Vec<WildCard> {
+    match cards(s) {
+        Some(cards) => cards,
+        None => panic!("invalid cards: {}", s),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::card::parse_cards;
+    use crate::card::wild::cards;
+    use crate::card::wild::WildCard;
+    use crate::card::Rank;
+
+    #[test]
+    fn test_cards() {
+        let natural = |s| WildCard::Natural(parse_cards(s)[0]);
+
+        assert_eq!(
+            cards("AhJkKd 🃏 2c").unwrap(),
+            vec![
+                natural("Ah"),
+                WildCard::Joker,
+                natural("Kd"),
+                WildCard::Joker,
+                natural("2c"),
+            ]
+        );
+        assert_eq!(cards("JdJK").unwrap(), vec![natural("Jd"), WildCard::Joker]);
+        assert_eq!(cards("AhJ"), None);
+        assert_eq!(WildCard::from_str("jk").unwrap(), WildCard::Joker);
+        assert_eq!(WildCard::Joker.to_string(), "Jk");
+    }
+
+    #[test]
+    fn test_from_str_invalid() {
+        for s in ["", "Zz", "AhKd", "JkJk", "Jkh"] {
+            let error = WildCard::from_str(s).unwrap_err();
+
+            assert_eq!(error.to_string(), format!("unknown card value: {}", s));
+        }
+    }
+
+    #[test]
+    fn test_is_wild() {
+        let deuce = WildCard::from_str("2d").unwrap();
+
+        assert!(deuce.is_wild(&[Rank::Two]));
+        assert!(!deuce.is_wild(&[]));
+        assert!(WildCard::Joker.is_wild(&[]));
+    }
+}
@@ -1,4 +1,3 @@
-use std::collections::HashMap;
 use std::convert::TryInto;
 
 use ::itertools::Itertools;
@@ -9,8 +8,24 @@ use crate::combination::Combination;
 use crate::game::Variant;
 
 impl Combination {
+    pub fn category(&self) -> &'static str {
+        match self {
+            Self::HighCard { .. } => "high_card",
+            Self::Pair { .. } => "pair",
+            Self::TwoPairs { .. } => "two_pairs",
+            Self::ThreeOfAKind { .. } => "three_of_a_kind",
+            Self::Straight { .. } => "straight",
+            Self::Flush { .. } => "flush",
+            Self::FullHouse { .. } => "full_house",
+            Self::FourOfAKind { .. } => "four_of_a_kind",
+            Self::StraightFlush { .. } => "straight_flush",
+            Self::FiveOfAKind { .. } => "five_of_a_kind",
+        }
+    }
+
     pub fn from_variant(variant: Variant) -> Self {
-        Self::try_straight_flush(variant)
+        Self::try_five_of_a_kind(variant)
+            .or_else(|| Self::try_straight_flush(variant))
             .or_else(|| Self::try_four_of_a_kind(variant))
             .or_else(|| Self::try_full_house(variant))
             .or_else(|| Self::try_flush(variant))
@@ -22,6 +37,17 @@ impl Combination {
             .unwrap()
     }
 
+    /// Five cards of one rank, which only wild cards can make.
+    pub fn try_five_of_a_kind(variant: Variant) -> Option<Self> {
+        let rank = variant.0[0].0;
+
+        if variant.0.iter().all(|card| card.0 == rank) {
+            Some(Self::FiveOfAKind { rank })
+        } else {
+            None
+        }
+    }
+
     pub fn try_straight_flush(variant: Variant) -> Option<Self> {
         Self::try_flush(variant)
             .and_then(|_flush| Self::try_straight(variant))
@@ -39,13 +65,11 @@ impl Combination {
 
         let rank = groups
             .clone()
-            .into_iter()
             .find(|(_rank, n)| *n == 4)
             .map(|(rank, _)| rank);
 
         let kicker = rank.and(
             groups
-                .into_iter()
                 .filter(|(_rank, n)| *n == 1)
                 .map(|(rank, _)| rank)
                 .max(),
@@ -63,13 +87,11 @@ impl Combination {
 
         let three = groups
             .clone()
-            .into_iter()
             .find(|(_rank, n)| *n == 3)
             .map(|(rank, _)| rank);
 
         let two = three.and(
             groups
-                .into_iter()
                 .filter(|(_rank, n)| *n == 2)
                 .map(|(rank, _)| rank)
                 .max(),
@@ -85,22 +107,18 @@ impl Combination {
         let suit = cards[0].1;
 
         if cards.iter().map(|card| card.1).all(|s| s == suit) {
-            let max = cards.iter().map(|card| card.0).max().unwrap();
-            Some(Self::Flush { rank: max })
+            let (rank, extra) = Self::split_ranks(cards);
+            Some(Self::Flush { rank, extra })
         } else {
             None
         }
     }
 
     pub fn try_straight(variant: Variant) -> Option<Self> {
-        let ranks = variant
-            .0
-            .iter()
-            .map(|card| card.0)
-            .sorted()
-            .collect::<Vec<_>>();
+        let mut ranks = variant.0.map(|card| card.0);
+        ranks.sort();
 
-        match ranks.as_slice() {
+        match ranks {
             [Rank::Two, Rank::Three, Rank::Four, Rank::Five, Rank::Ace] => {
                 Some(Self::Straight { rank: Rank::Ace })
             }
@@ -140,15 +158,14 @@ impl Combination {
 
         let rank = groups
             .clone()
-            .into_iter()
             .find(|(_rank, n)| *n == 3)
             .map(|(rank, _)| rank);
 
         let extra = rank.and(Some(
             groups
-                .into_iter()
                 .filter(|(_rank, n)| *n == 1)
                 .map(|(rank, _)| rank)
+                .sorted_by(|a, b| b.cmp(a))
                 .collect::<Vec<_>>(),
         ));
 
@@ -163,11 +180,10 @@ impl Combination {
     }
 
     pub fn try_two_pairs(variant: Variant) -> Option<Self> {
-        let groups = Self::group_ranks(variant.0);
+        let mut groups = Self::group_ranks(variant.0);
 
         let ranks = groups
             .clone()
-            .into_iter()
             .filter(|(_rank, n)| *n == 2)
             .map(|(rank, _)| rank)
             .collect::<Vec<_>>();
@@ -176,7 +192,6 @@ impl Combination {
             None
         } else {
             let kicker = groups
-                .into_iter()
                 .find(|(_rank, n)| *n == 1)
                 .map(|(rank, _)| rank)
                 .unwrap();
@@ -202,15 +217,14 @@ impl Combination {
 
         let rank = groups
             .clone()
-            .into_iter()
             .find(|(_rank, n)| *n == 2)
             .map(|(rank, _)| rank);
 
         let extra = rank.and(Some(
             groups
-                .into_iter()
                 .filter(|(_rank, n)| *n == 1)
                 .map(|(rank, _)| rank)
+                .sorted_by(|a, b| b.cmp(a))
                 .collect::<Vec<_>>(),
         ));
 
@@ -225,31 +239,38 @@ impl Combination {
     }
 
     pub fn try_high_card(variant: Variant) -> Option<Self> {
-        let cards = &variant.0;
+        let (rank, extra) = Self::split_ranks(&variant.0);
 
-        let rank = cards.iter().map(|card| card.0).max().unwrap();
+        Some(Self::HighCard { rank, extra })
+    }
 
-        Some(Self::HighCard { rank })
+    /// The highest rank and the four others, from the highest.
+    fn split_ranks(cards: &[Card; 5]) -> (Rank, [Rank; 4]) {
+        let mut ranks = cards.map(|card| card.0);
+        ranks.sort_by(|a, b| b.cmp(a));
+
+        (ranks[0], [ranks[1], ranks[2], ranks[3], ranks[4]])
     }
 
-    fn group_ranks(cards: [Card; 5]) -> HashMap<Rank, u64> {
-        cards
-            .iter()
-            .map(|card| card.0)
-            .fold(HashMap::new(), |mut acc, x| {
-                let n = acc.get(&x).map_or(1, |n| n + 1);
+    /// The ranks held and how many times, from the lowest.
+    fn group_ranks(cards: [Card; 5]) -> impl Iterator<Item = (Rank, u64)> + Clone {
+        let mut counts = [0; 13];
 
-                acc.insert(x, n);
+        for card in &cards {
+            counts[card.0 as usize] += 1;
+        }
 
-                acc
-            })
+        Rank::ALL
+            .iter()
+            .copied()
+            .zip(counts)
+            .filter(|(_rank, n)| *n > 0)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use ::claim::*;
-    use ::itertools::Itertools;
 
     use crate::card::Card;
     use crate::card::Rank;
@@ -270,7 +291,13 @@ mod tests {
         let result = Combination::try_high_card(variant);
 
         assert_some!(result);
-        assert_eq!(result.unwrap(), Combination::HighCard { rank: Rank::Ace });
+        assert_eq!(
+            result.unwrap(),
+            Combination::HighCard {
+                rank: Rank::Ace,
+                extra: [Rank::Jack, Rank::Eight, Rank::Seven, Rank::Two]
+            }
+        );
     }
 
     #[test]
@@ -573,7 +600,13 @@ mod tests {
         let result = Combination::try_flush(variant);
 
         assert_some!(result);
-        assert_eq!(result.unwrap(), Combination::Flush { rank: Rank::Ace });
+        assert_eq!(
+            result.unwrap(),
+            Combination::Flush {
+                rank: Rank::Ace,
+                extra: [Rank::Jack, Rank::Eight, Rank::Seven, Rank::Two]
+            }
+        );
     }
 
     #[test]
@@ -880,8 +913,6 @@ mod tests {
                 Card(Rank::King, Suit::Hearts),
                 Card(Rank::Seven, Suit::Hearts),
             ])
-            .into_iter()
-            .sorted_by(|(rank_a, _), (rank_b, _)| rank_a.cmp(rank_b))
             .collect::<Vec<(_, _)>>(),
             vec![
                 (Rank::Seven, 1),
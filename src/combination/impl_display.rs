@@ -0,0 +1,101 @@
+use std::fmt;
+
+use super::Combination;
+
+use crate::card::Rank;
+
+/// Reads as a player would say it: `full house, kings full of fours`.
+impl fmt::Display for Combination {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Combination::HighCard { rank, .. } => write!(f, "high card, {}", name(*rank)),
+            Combination::Pair { rank, .. } => write!(f, "pair of {}", plural(*rank)),
+            Combination::TwoPairs { low, high, .. } => {
+                write!(f, "two pairs, {} and {}", plural(*high), plural(*low))
+            }
+            Combination::ThreeOfAKind { rank, .. } => {
+                write!(f, "three of a kind, {}", plural(*rank))
+            }
+            Combination::Straight { rank } => write!(f, "straight, {}", straight(*rank)),
+            Combination::Flush { rank, .. } => write!(f, "flush, {} high", name(*rank)),
+            Combination::FullHouse { two, three } => {
+                write!(f, "full house, {} full of {}", plural(*three), plural(*two))
+            }
+            Combination::FourOfAKind { rank, .. } => {
+                write!(f, "four of a kind, {}", plural(*rank))
+            }
+            Combination::StraightFlush { rank: Rank::Ten } => write!(f, "royal flush"),
+            Combination::StraightFlush { rank } => {
+                write!(f, "straight flush, {}", straight(*rank))
+            }
+            Combination::FiveOfAKind { rank } => {
+                write!(f, "five of a kind, {}", plural(*rank))
+            }
+        }
+    }
+}
+
+/// Straights are identified by their lowest rank, `Ace` for the wheel.
+fn straight(low: Rank) -> String {
+    let high = match low {
+        Rank::Ace => Rank::Five,
+        _ => Rank::ALL[Rank::ALL.iter().position(|rank| *rank == low).unwrap() + 4],
+    };
+
+    format!("{} to {}", name(low), name(high))
+}
+
+pub(crate) fn name(rank: Rank) -> &'static str {
+    match rank {
+        Rank::Two => "two",
+        Rank::Three => "three",
+        Rank::Four => "four",
+        Rank::Five => "five",
+        Rank::Six => "six",
+        Rank::Seven => "seven",
+        Rank::Eight => "eight",
+        Rank::Nine => "nine",
+        Rank::Ten => "ten",
+        Rank::Jack => "jack",
+        Rank::Queen => "queen",
+        Rank::King => "king",
+        Rank::Ace => "ace",
+    }
+}
+
+pub(crate) fn plural(rank: Rank) -> String {
+    match rank {
+        Rank::Six => "sixes".to_string(),
+        _ => format!("{}s", name(rank)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryInto;
+
+    use crate::card::parse_cards;
+    use crate::combination::Combination;
+    use crate::game::Variant;
+
+    fn describe(cards: &str) -> String {
+        let variant = Variant(parse_cards(cards).try_into().unwrap());
+
+        Combination::from_variant(variant).to_string()
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(describe("As9d7c4h2s"), "high card, ace");
+        assert_eq!(describe("KsKd7c4h2s"), "pair of kings");
+        assert_eq!(describe("KsKd4c4h2s"), "two pairs, kings and fours");
+        assert_eq!(describe("6s6d6c4h2s"), "three of a kind, sixes");
+        assert_eq!(describe("As2d3c4h5s"), "straight, ace to five");
+        assert_eq!(describe("9s8d7c6h5s"), "straight, five to nine");
+        assert_eq!(describe("Ah9h7h4h2h"), "flush, ace high");
+        assert_eq!(describe("4s4dKcKhKs"), "full house, kings full of fours");
+        assert_eq!(describe("9s9d9c9hKs"), "four of a kind, nines");
+        assert_eq!(describe("9h8h7h6h5h"), "straight flush, five to nine");
+        assert_eq!(describe("AhKhQhJhTh"), "royal flush");
+    }
+}
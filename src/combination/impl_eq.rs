@@ -11,9 +11,16 @@ impl PartialEq for Combination {
             false
         } else {
             match self {
-                Self::HighCard { rank: rank_a } => {
-                    if let Self::HighCard { rank: rank_b } = other {
-                        rank_a == rank_b
+                Self::HighCard {
+                    rank: rank_a,
+                    extra: extra_a,
+                } => {
+                    if let Self::HighCard {
+                        rank: rank_b,
+                        extra: extra_b,
+                    } = other
+                    {
+                        rank_a == rank_b && compare_extra(extra_a, extra_b)
                     } else {
                         false
                     }
@@ -71,9 +78,16 @@ impl PartialEq for Combination {
                         false
                     }
                 }
-                Self::Flush { rank: rank_a } => {
-                    if let Self::Flush { rank: rank_b } = other {
-                        rank_a == rank_b
+                Self::Flush {
+                    rank: rank_a,
+                    extra: extra_a,
+                } => {
+                    if let Self::Flush {
+                        rank: rank_b,
+                        extra: extra_b,
+                    } = other
+                    {
+                        rank_a == rank_b && compare_extra(extra_a, extra_b)
                     } else {
                         false
                     }
@@ -113,6 +127,13 @@ impl PartialEq for Combination {
                         false
                     }
                 }
+                Self::FiveOfAKind { rank: rank_a } => {
+                    if let Self::FiveOfAKind { rank: rank_b } = other {
+                        rank_a == rank_b
+                    } else {
+                        false
+                    }
+                }
             }
         }
     }
@@ -136,7 +157,10 @@ mod tests {
 
     #[test]
     fn test_compare_high_card_with_different_combinations() {
-        let lhs = Combination::HighCard { rank: Rank::Two };
+        let lhs = Combination::HighCard {
+            rank: Rank::Two,
+            extra: [Rank::Three, Rank::Four, Rank::Five, Rank::Six],
+        };
 
         assert_ne!(
             lhs,
@@ -161,7 +185,13 @@ mod tests {
             }
         );
         assert_ne!(lhs, Combination::Straight { rank: Rank::Two });
-        assert_ne!(lhs, Combination::Flush { rank: Rank::Two });
+        assert_ne!(
+            lhs,
+            Combination::Flush {
+                rank: Rank::Two,
+                extra: [Rank::Three, Rank::Four, Rank::Five, Rank::Six]
+            }
+        );
         assert_ne!(
             lhs,
             Combination::FullHouse {
@@ -181,10 +211,25 @@ mod tests {
 
     #[test]
     fn test_compare_high_card_with_high_card() {
-        let lhs = Combination::HighCard { rank: Rank::Two };
+        let lhs = Combination::HighCard {
+            rank: Rank::Two,
+            extra: [Rank::Three, Rank::Four, Rank::Five, Rank::Six],
+        };
 
-        assert_eq!(lhs, Combination::HighCard { rank: Rank::Two });
-        assert_ne!(lhs, Combination::HighCard { rank: Rank::Three });
+        assert_eq!(
+            lhs,
+            Combination::HighCard {
+                rank: Rank::Two,
+                extra: [Rank::Three, Rank::Four, Rank::Five, Rank::Six]
+            }
+        );
+        assert_ne!(
+            lhs,
+            Combination::HighCard {
+                rank: Rank::Three,
+                extra: [Rank::Two, Rank::Four, Rank::Five, Rank::Six]
+            }
+        );
     }
 
     #[test]
@@ -194,7 +239,13 @@ mod tests {
             extra: [Rank::Three, Rank::Four, Rank::Five],
         };
 
-        assert_ne!(lhs, Combination::HighCard { rank: Rank::Two });
+        assert_ne!(
+            lhs,
+            Combination::HighCard {
+                rank: Rank::Two,
+                extra: [Rank::Three, Rank::Four, Rank::Five, Rank::Six]
+            }
+        );
         assert_ne!(
             lhs,
             Combination::TwoPairs {
@@ -211,7 +262,13 @@ mod tests {
             }
         );
         assert_ne!(lhs, Combination::Straight { rank: Rank::Two });
-        assert_ne!(lhs, Combination::Flush { rank: Rank::Two });
+        assert_ne!(
+            lhs,
+            Combination::Flush {
+                rank: Rank::Two,
+                extra: [Rank::Three, Rank::Four, Rank::Five, Rank::Six]
+            }
+        );
         assert_ne!(
             lhs,
             Combination::FullHouse {
@@ -317,7 +374,13 @@ mod tests {
             extra: [Rank::Four],
         };
 
-        assert_ne!(lhs, Combination::HighCard { rank: Rank::Two });
+        assert_ne!(
+            lhs,
+            Combination::HighCard {
+                rank: Rank::Two,
+                extra: [Rank::Three, Rank::Four, Rank::Five, Rank::Six]
+            }
+        );
         assert_ne!(
             lhs,
             Combination::Pair {
@@ -333,7 +396,13 @@ mod tests {
             }
         );
         assert_ne!(lhs, Combination::Straight { rank: Rank::Two });
-        assert_ne!(lhs, Combination::Flush { rank: Rank::Two });
+        assert_ne!(
+            lhs,
+            Combination::Flush {
+                rank: Rank::Two,
+                extra: [Rank::Three, Rank::Four, Rank::Five, Rank::Six]
+            }
+        );
         assert_ne!(
             lhs,
             Combination::FullHouse {
@@ -400,7 +469,13 @@ mod tests {
             extra: [Rank::Three, Rank::Four],
         };
 
-        assert_ne!(lhs, Combination::HighCard { rank: Rank::Two });
+        assert_ne!(
+            lhs,
+            Combination::HighCard {
+                rank: Rank::Two,
+                extra: [Rank::Three, Rank::Four, Rank::Five, Rank::Six]
+            }
+        );
         assert_ne!(
             lhs,
             Combination::Pair {
@@ -417,7 +492,13 @@ mod tests {
             }
         );
         assert_ne!(lhs, Combination::Straight { rank: Rank::Two });
-        assert_ne!(lhs, Combination::Flush { rank: Rank::Two });
+        assert_ne!(
+            lhs,
+            Combination::Flush {
+                rank: Rank::Two,
+                extra: [Rank::Three, Rank::Four, Rank::Five, Rank::Six]
+            }
+        );
         assert_ne!(
             lhs,
             Combination::FullHouse {
@@ -483,7 +564,13 @@ mod tests {
     fn test_compare_straight_with_different_combinations() {
         let lhs = Combination::Straight { rank: Rank::Two };
 
-        assert_ne!(lhs, Combination::HighCard { rank: Rank::Two });
+        assert_ne!(
+            lhs,
+            Combination::HighCard {
+                rank: Rank::Two,
+                extra: [Rank::Three, Rank::Four, Rank::Five, Rank::Six]
+            }
+        );
         assert_ne!(
             lhs,
             Combination::Pair {
@@ -506,7 +593,13 @@ mod tests {
                 extra: [Rank::Three, Rank::Four]
             }
         );
-        assert_ne!(lhs, Combination::Flush { rank: Rank::Two });
+        assert_ne!(
+            lhs,
+            Combination::Flush {
+                rank: Rank::Two,
+                extra: [Rank::Three, Rank::Four, Rank::Five, Rank::Six]
+            }
+        );
         assert_ne!(
             lhs,
             Combination::FullHouse {
@@ -534,9 +627,18 @@ mod tests {
 
     #[test]
     fn test_compare_flush_with_different_combinations() {
-        let lhs = Combination::Flush { rank: Rank::Two };
+        let lhs = Combination::Flush {
+            rank: Rank::Two,
+            extra: [Rank::Three, Rank::Four, Rank::Five, Rank::Six],
+        };
 
-        assert_ne!(lhs, Combination::HighCard { rank: Rank::Two });
+        assert_ne!(
+            lhs,
+            Combination::HighCard {
+                rank: Rank::Two,
+                extra: [Rank::Three, Rank::Four, Rank::Five, Rank::Six]
+            }
+        );
         assert_ne!(
             lhs,
             Combination::Pair {
@@ -579,10 +681,25 @@ mod tests {
 
     #[test]
     fn test_compare_flush_with_flush() {
-        let lhs = Combination::Flush { rank: Rank::Two };
+        let lhs = Combination::Flush {
+            rank: Rank::Two,
+            extra: [Rank::Three, Rank::Four, Rank::Five, Rank::Six],
+        };
 
-        assert_eq!(lhs, Combination::Flush { rank: Rank::Two });
-        assert_ne!(lhs, Combination::Flush { rank: Rank::Three });
+        assert_eq!(
+            lhs,
+            Combination::Flush {
+                rank: Rank::Two,
+                extra: [Rank::Three, Rank::Four, Rank::Five, Rank::Six]
+            }
+        );
+        assert_ne!(
+            lhs,
+            Combination::Flush {
+                rank: Rank::Three,
+                extra: [Rank::Two, Rank::Four, Rank::Five, Rank::Six]
+            }
+        );
     }
 
     #[test]
@@ -592,7 +709,13 @@ mod tests {
             three: Rank::Three,
         };
 
-        assert_ne!(lhs, Combination::HighCard { rank: Rank::Two });
+        assert_ne!(
+            lhs,
+            Combination::HighCard {
+                rank: Rank::Two,
+                extra: [Rank::Three, Rank::Four, Rank::Five, Rank::Six]
+            }
+        );
         assert_ne!(
             lhs,
             Combination::Pair {
@@ -616,7 +739,13 @@ mod tests {
             }
         );
         assert_ne!(lhs, Combination::Straight { rank: Rank::Two });
-        assert_ne!(lhs, Combination::Flush { rank: Rank::Two });
+        assert_ne!(
+            lhs,
+            Combination::Flush {
+                rank: Rank::Two,
+                extra: [Rank::Three, Rank::Four, Rank::Five, Rank::Six]
+            }
+        );
         assert_ne!(
             lhs,
             Combination::FourOfAKind {
@@ -671,7 +800,13 @@ mod tests {
             extra: [Rank::Three],
         };
 
-        assert_ne!(lhs, Combination::HighCard { rank: Rank::Two });
+        assert_ne!(
+            lhs,
+            Combination::HighCard {
+                rank: Rank::Two,
+                extra: [Rank::Three, Rank::Four, Rank::Five, Rank::Six]
+            }
+        );
         assert_ne!(
             lhs,
             Combination::Pair {
@@ -695,7 +830,13 @@ mod tests {
             }
         );
         assert_ne!(lhs, Combination::Straight { rank: Rank::Two });
-        assert_ne!(lhs, Combination::Flush { rank: Rank::Two });
+        assert_ne!(
+            lhs,
+            Combination::Flush {
+                rank: Rank::Two,
+                extra: [Rank::Three, Rank::Four, Rank::Five, Rank::Six]
+            }
+        );
         assert_ne!(
             lhs,
             Combination::FullHouse {
@@ -747,7 +888,13 @@ mod tests {
     fn test_compare_straight_flush_with_different_combinations() {
         let lhs = Combination::StraightFlush { rank: Rank::Two };
 
-        assert_ne!(lhs, Combination::HighCard { rank: Rank::Two });
+        assert_ne!(
+            lhs,
+            Combination::HighCard {
+                rank: Rank::Two,
+                extra: [Rank::Three, Rank::Four, Rank::Five, Rank::Six]
+            }
+        );
         assert_ne!(
             lhs,
             Combination::Pair {
@@ -771,7 +918,13 @@ mod tests {
             }
         );
         assert_ne!(lhs, Combination::Straight { rank: Rank::Two });
-        assert_ne!(lhs, Combination::Flush { rank: Rank::Two });
+        assert_ne!(
+            lhs,
+            Combination::Flush {
+                rank: Rank::Two,
+                extra: [Rank::Three, Rank::Four, Rank::Five, Rank::Six]
+            }
+        );
         assert_ne!(
             lhs,
             Combination::FullHouse {
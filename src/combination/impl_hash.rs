@@ -8,8 +8,9 @@ impl Hash for Combination {
     fn hash<H: Hasher>(&self, state: &mut H) {
         std::mem::discriminant(self).hash(state);
         match self {
-            Combination::HighCard { rank } => {
+            Combination::HighCard { rank, extra } => {
                 rank.hash(state);
+                extra.iter().sorted().collect::<Vec<_>>().hash(state);
             }
             Combination::Pair { rank, extra } => {
                 rank.hash(state);
@@ -27,8 +28,9 @@ impl Hash for Combination {
             Combination::Straight { rank } => {
                 rank.hash(state);
             }
-            Combination::Flush { rank } => {
+            Combination::Flush { rank, extra } => {
                 rank.hash(state);
+                extra.iter().sorted().collect::<Vec<_>>().hash(state);
             }
             Combination::FullHouse { two, three } => {
                 two.hash(state);
@@ -41,6 +43,9 @@ impl Hash for Combination {
             Combination::StraightFlush { rank } => {
                 rank.hash(state);
             }
+            Combination::FiveOfAKind { rank } => {
+                rank.hash(state);
+            }
         }
     }
 }
@@ -61,7 +66,10 @@ mod tests {
 
     #[test]
     fn test_compare_high_card_hash_with_different_combination_hashes() {
-        let lhs = Combination::HighCard { rank: Rank::Two };
+        let lhs = Combination::HighCard {
+            rank: Rank::Two,
+            extra: [Rank::Three, Rank::Four, Rank::Five, Rank::Six],
+        };
 
         assert_ne!(calculate_hash(&lhs), {
             let rhs = Combination::Pair {
@@ -90,7 +98,10 @@ mod tests {
             calculate_hash(&rhs)
         });
         assert_ne!(calculate_hash(&lhs), {
-            let rhs = Combination::Flush { rank: Rank::Two };
+            let rhs = Combination::Flush {
+                rank: Rank::Two,
+                extra: [Rank::Three, Rank::Four, Rank::Five, Rank::Six],
+            };
             calculate_hash(&rhs)
         });
         assert_ne!(calculate_hash(&lhs), {
@@ -115,14 +126,23 @@ mod tests {
 
     #[test]
     fn test_compare_high_card_hash_with_high_card_hash() {
-        let lhs = Combination::HighCard { rank: Rank::Two };
+        let lhs = Combination::HighCard {
+            rank: Rank::Two,
+            extra: [Rank::Three, Rank::Four, Rank::Five, Rank::Six],
+        };
 
         assert_eq!(calculate_hash(&lhs), {
-            let rhs = Combination::HighCard { rank: Rank::Two };
+            let rhs = Combination::HighCard {
+                rank: Rank::Two,
+                extra: [Rank::Three, Rank::Four, Rank::Five, Rank::Six],
+            };
             calculate_hash(&rhs)
         });
         assert_ne!(calculate_hash(&lhs), {
-            let rhs = Combination::HighCard { rank: Rank::Three };
+            let rhs = Combination::HighCard {
+                rank: Rank::Three,
+                extra: [Rank::Two, Rank::Four, Rank::Five, Rank::Six],
+            };
             calculate_hash(&rhs)
         });
     }
@@ -135,7 +155,10 @@ mod tests {
         };
 
         assert_ne!(calculate_hash(&lhs), {
-            let rhs = Combination::HighCard { rank: Rank::Two };
+            let rhs = Combination::HighCard {
+                rank: Rank::Two,
+                extra: [Rank::Three, Rank::Four, Rank::Five, Rank::Six],
+            };
             calculate_hash(&rhs)
         });
         assert_ne!(calculate_hash(&lhs), {
@@ -158,7 +181,10 @@ mod tests {
             calculate_hash(&rhs)
         });
         assert_ne!(calculate_hash(&lhs), {
-            let rhs = Combination::Flush { rank: Rank::Two };
+            let rhs = Combination::Flush {
+                rank: Rank::Two,
+                extra: [Rank::Three, Rank::Four, Rank::Five, Rank::Six],
+            };
             calculate_hash(&rhs)
         });
         assert_ne!(calculate_hash(&lhs), {
@@ -270,7 +296,10 @@ mod tests {
         };
 
         assert_ne!(calculate_hash(&lhs), {
-            let rhs = Combination::HighCard { rank: Rank::Two };
+            let rhs = Combination::HighCard {
+                rank: Rank::Two,
+                extra: [Rank::Three, Rank::Four, Rank::Five, Rank::Six],
+            };
             calculate_hash(&rhs)
         });
         assert_ne!(calculate_hash(&lhs), {
@@ -292,7 +321,10 @@ mod tests {
             calculate_hash(&rhs)
         });
         assert_ne!(calculate_hash(&lhs), {
-            let rhs = Combination::Flush { rank: Rank::Two };
+            let rhs = Combination::Flush {
+                rank: Rank::Two,
+                extra: [Rank::Three, Rank::Four, Rank::Five, Rank::Six],
+            };
             calculate_hash(&rhs)
         });
         assert_ne!(calculate_hash(&lhs), {
@@ -365,7 +397,10 @@ mod tests {
         };
 
         assert_ne!(calculate_hash(&lhs), {
-            let rhs = Combination::HighCard { rank: Rank::Two };
+            let rhs = Combination::HighCard {
+                rank: Rank::Two,
+                extra: [Rank::Three, Rank::Four, Rank::Five, Rank::Six],
+            };
             calculate_hash(&rhs)
         });
         assert_ne!(calculate_hash(&lhs), {
@@ -388,7 +423,10 @@ mod tests {
             calculate_hash(&rhs)
         });
         assert_ne!(calculate_hash(&lhs), {
-            let rhs = Combination::Flush { rank: Rank::Two };
+            let rhs = Combination::Flush {
+                rank: Rank::Two,
+                extra: [Rank::Three, Rank::Four, Rank::Five, Rank::Six],
+            };
             calculate_hash(&rhs)
         });
         assert_ne!(calculate_hash(&lhs), {
@@ -460,7 +498,10 @@ mod tests {
         let lhs = Combination::Straight { rank: Rank::Two };
 
         assert_ne!(calculate_hash(&lhs), {
-            let rhs = Combination::HighCard { rank: Rank::Two };
+            let rhs = Combination::HighCard {
+                rank: Rank::Two,
+                extra: [Rank::Three, Rank::Four, Rank::Five, Rank::Six],
+            };
             calculate_hash(&rhs)
         });
         assert_ne!(calculate_hash(&lhs), {
@@ -486,7 +527,10 @@ mod tests {
             calculate_hash(&rhs)
         });
         assert_ne!(calculate_hash(&lhs), {
-            let rhs = Combination::Flush { rank: Rank::Two };
+            let rhs = Combination::Flush {
+                rank: Rank::Two,
+                extra: [Rank::Three, Rank::Four, Rank::Five, Rank::Six],
+            };
             calculate_hash(&rhs)
         });
         assert_ne!(calculate_hash(&lhs), {
@@ -525,10 +569,16 @@ mod tests {
 
     #[test]
     fn test_compare_flush_hash_with_different_combination_hashes() {
-        let lhs = Combination::Flush { rank: Rank::Two };
+        let lhs = Combination::Flush {
+            rank: Rank::Two,
+            extra: [Rank::Three, Rank::Four, Rank::Five, Rank::Six],
+        };
 
         assert_ne!(calculate_hash(&lhs), {
-            let rhs = Combination::HighCard { rank: Rank::Two };
+            let rhs = Combination::HighCard {
+                rank: Rank::Two,
+                extra: [Rank::Three, Rank::Four, Rank::Five, Rank::Six],
+            };
             calculate_hash(&rhs)
         });
         assert_ne!(calculate_hash(&lhs), {
@@ -579,14 +629,23 @@ mod tests {
 
     #[test]
     fn test_compare_flush_hash_with_flush_hash() {
-        let lhs = Combination::Flush { rank: Rank::Two };
+        let lhs = Combination::Flush {
+            rank: Rank::Two,
+            extra: [Rank::Three, Rank::Four, Rank::Five, Rank::Six],
+        };
 
         assert_eq!(calculate_hash(&lhs), {
-            let rhs = Combination::Flush { rank: Rank::Two };
+            let rhs = Combination::Flush {
+                rank: Rank::Two,
+                extra: [Rank::Three, Rank::Four, Rank::Five, Rank::Six],
+            };
             calculate_hash(&rhs)
         });
         assert_ne!(calculate_hash(&lhs), {
-            let rhs = Combination::Flush { rank: Rank::Three };
+            let rhs = Combination::Flush {
+                rank: Rank::Three,
+                extra: [Rank::Two, Rank::Four, Rank::Five, Rank::Six],
+            };
             calculate_hash(&rhs)
         });
     }
@@ -599,7 +658,10 @@ mod tests {
         };
 
         assert_ne!(calculate_hash(&lhs), {
-            let rhs = Combination::HighCard { rank: Rank::Two };
+            let rhs = Combination::HighCard {
+                rank: Rank::Two,
+                extra: [Rank::Three, Rank::Four, Rank::Five, Rank::Six],
+            };
             calculate_hash(&rhs)
         });
         assert_ne!(calculate_hash(&lhs), {
@@ -629,7 +691,10 @@ mod tests {
             calculate_hash(&rhs)
         });
         assert_ne!(calculate_hash(&lhs), {
-            let rhs = Combination::Flush { rank: Rank::Two };
+            let rhs = Combination::Flush {
+                rank: Rank::Two,
+                extra: [Rank::Three, Rank::Four, Rank::Five, Rank::Six],
+            };
             calculate_hash(&rhs)
         });
         assert_ne!(calculate_hash(&lhs), {
@@ -690,7 +755,10 @@ mod tests {
         };
 
         assert_ne!(calculate_hash(&lhs), {
-            let rhs = Combination::HighCard { rank: Rank::Two };
+            let rhs = Combination::HighCard {
+                rank: Rank::Two,
+                extra: [Rank::Three, Rank::Four, Rank::Five, Rank::Six],
+            };
             calculate_hash(&rhs)
         });
         assert_ne!(calculate_hash(&lhs), {
@@ -720,7 +788,10 @@ mod tests {
             calculate_hash(&rhs)
         });
         assert_ne!(calculate_hash(&lhs), {
-            let rhs = Combination::Flush { rank: Rank::Two };
+            let rhs = Combination::Flush {
+                rank: Rank::Two,
+                extra: [Rank::Three, Rank::Four, Rank::Five, Rank::Six],
+            };
             calculate_hash(&rhs)
         });
         assert_ne!(calculate_hash(&lhs), {
@@ -778,7 +849,10 @@ mod tests {
         let lhs = Combination::StraightFlush { rank: Rank::Two };
 
         assert_ne!(calculate_hash(&lhs), {
-            let rhs = Combination::HighCard { rank: Rank::Two };
+            let rhs = Combination::HighCard {
+                rank: Rank::Two,
+                extra: [Rank::Three, Rank::Four, Rank::Five, Rank::Six],
+            };
             calculate_hash(&rhs)
         });
         assert_ne!(calculate_hash(&lhs), {
@@ -808,7 +882,10 @@ mod tests {
             calculate_hash(&rhs)
         });
         assert_ne!(calculate_hash(&lhs), {
-            let rhs = Combination::Flush { rank: Rank::Two };
+            let rhs = Combination::Flush {
+                rank: Rank::Two,
+                extra: [Rank::Three, Rank::Four, Rank::Five, Rank::Six],
+            };
             calculate_hash(&rhs)
         });
         assert_ne!(calculate_hash(&lhs), {
@@ -840,4 +917,22 @@ mod tests {
             calculate_hash(&rhs)
         });
     }
+
+    #[test]
+    fn test_compare_five_of_a_kind_hash() {
+        let lhs = Combination::FiveOfAKind { rank: Rank::Two };
+
+        assert_eq!(calculate_hash(&lhs), {
+            let rhs = Combination::FiveOfAKind { rank: Rank::Two };
+            calculate_hash(&rhs)
+        });
+        assert_ne!(calculate_hash(&lhs), {
+            let rhs = Combination::StraightFlush { rank: Rank::Two };
+            calculate_hash(&rhs)
+        });
+        assert_ne!(calculate_hash(&lhs), {
+            let rhs = Combination::FiveOfAKind { rank: Rank::Three };
+            calculate_hash(&rhs)
+        });
+    }
 }
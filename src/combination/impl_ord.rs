@@ -7,184 +7,215 @@ use ::itertools::Itertools;
 
 impl PartialOrd for Combination {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Combination {
+    fn cmp(&self, other: &Self) -> Ordering {
         match self {
-            Self::HighCard { rank: rank_a } => match other {
-                Self::HighCard { rank: rank_b } => rank_a.partial_cmp(rank_b),
-                Self::Pair { .. } => Some(Ordering::Less),
-                Self::TwoPairs { .. } => Some(Ordering::Less),
-                Self::ThreeOfAKind { .. } => Some(Ordering::Less),
-                Self::Straight { .. } => Some(Ordering::Less),
-                Self::Flush { .. } => Some(Ordering::Less),
-                Self::FullHouse { .. } => Some(Ordering::Less),
-                Self::FourOfAKind { .. } => Some(Ordering::Less),
-                Self::StraightFlush { .. } => Some(Ordering::Less),
+            Self::HighCard {
+                rank: rank_a,
+                extra: extra_a,
+            } => match other {
+                Self::HighCard {
+                    rank: rank_b,
+                    extra: extra_b,
+                } => match rank_a.cmp(rank_b) {
+                    Ordering::Equal => compare_extra(extra_a, extra_b),
+                    ord => ord,
+                },
+                Self::Pair { .. } => Ordering::Less,
+                Self::TwoPairs { .. } => Ordering::Less,
+                Self::ThreeOfAKind { .. } => Ordering::Less,
+                Self::Straight { .. } => Ordering::Less,
+                Self::Flush { .. } => Ordering::Less,
+                Self::FullHouse { .. } => Ordering::Less,
+                Self::FourOfAKind { .. } => Ordering::Less,
+                Self::StraightFlush { .. } => Ordering::Less,
+                Self::FiveOfAKind { .. } => Ordering::Less,
             },
             Self::Pair {
                 rank: pair_rank_a,
                 extra: extra_a,
             } => match other {
-                Self::HighCard { .. } => Some(Ordering::Greater),
+                Self::HighCard { .. } => Ordering::Greater,
                 Self::Pair {
                     rank: pair_rank_b,
                     extra: extra_b,
-                } => match pair_rank_a.partial_cmp(pair_rank_b) {
-                    Some(Ordering::Equal) => Some(compare_extra(extra_a, extra_b)),
+                } => match pair_rank_a.cmp(pair_rank_b) {
+                    Ordering::Equal => compare_extra(extra_a, extra_b),
                     ord => ord,
                 },
-                Self::TwoPairs { .. } => Some(Ordering::Less),
-                Self::ThreeOfAKind { .. } => Some(Ordering::Less),
-                Self::Straight { .. } => Some(Ordering::Less),
-                Self::Flush { .. } => Some(Ordering::Less),
-                Self::FullHouse { .. } => Some(Ordering::Less),
-                Self::FourOfAKind { .. } => Some(Ordering::Less),
-                Self::StraightFlush { .. } => Some(Ordering::Less),
+                Self::TwoPairs { .. } => Ordering::Less,
+                Self::ThreeOfAKind { .. } => Ordering::Less,
+                Self::Straight { .. } => Ordering::Less,
+                Self::Flush { .. } => Ordering::Less,
+                Self::FullHouse { .. } => Ordering::Less,
+                Self::FourOfAKind { .. } => Ordering::Less,
+                Self::StraightFlush { .. } => Ordering::Less,
+                Self::FiveOfAKind { .. } => Ordering::Less,
             },
             Self::TwoPairs {
                 low: low_pair_rank_a,
                 high: high_pair_rank_a,
                 extra: extra_a,
             } => match other {
-                Self::HighCard { .. } => Some(Ordering::Greater),
-                Self::Pair { .. } => Some(Ordering::Greater),
+                Self::HighCard { .. } => Ordering::Greater,
+                Self::Pair { .. } => Ordering::Greater,
                 Self::TwoPairs {
                     low: low_pair_rank_b,
                     high: high_pair_rank_b,
                     extra: extra_b,
-                } => match high_pair_rank_a.partial_cmp(high_pair_rank_b) {
-                    Some(Ordering::Equal) => match low_pair_rank_a.partial_cmp(low_pair_rank_b) {
-                        Some(Ordering::Equal) => Some(compare_extra(extra_a, extra_b)),
+                } => match high_pair_rank_a.cmp(high_pair_rank_b) {
+                    Ordering::Equal => match low_pair_rank_a.cmp(low_pair_rank_b) {
+                        Ordering::Equal => compare_extra(extra_a, extra_b),
                         ord => ord,
                     },
                     ord => ord,
                 },
-                Self::ThreeOfAKind { .. } => Some(Ordering::Less),
-                Self::Straight { .. } => Some(Ordering::Less),
-                Self::Flush { .. } => Some(Ordering::Less),
-                Self::FullHouse { .. } => Some(Ordering::Less),
-                Self::FourOfAKind { .. } => Some(Ordering::Less),
-                Self::StraightFlush { .. } => Some(Ordering::Less),
+                Self::ThreeOfAKind { .. } => Ordering::Less,
+                Self::Straight { .. } => Ordering::Less,
+                Self::Flush { .. } => Ordering::Less,
+                Self::FullHouse { .. } => Ordering::Less,
+                Self::FourOfAKind { .. } => Ordering::Less,
+                Self::StraightFlush { .. } => Ordering::Less,
+                Self::FiveOfAKind { .. } => Ordering::Less,
             },
             Self::ThreeOfAKind {
                 rank: rank_a,
                 extra: extra_a,
             } => match other {
-                Self::HighCard { .. } => Some(Ordering::Greater),
-                Self::Pair { .. } => Some(Ordering::Greater),
-                Self::TwoPairs { .. } => Some(Ordering::Greater),
+                Self::HighCard { .. } => Ordering::Greater,
+                Self::Pair { .. } => Ordering::Greater,
+                Self::TwoPairs { .. } => Ordering::Greater,
                 Self::ThreeOfAKind {
                     rank: rank_b,
                     extra: extra_b,
-                } => match rank_a.partial_cmp(rank_b) {
-                    Some(Ordering::Equal) => Some(compare_extra(extra_a, extra_b)),
+                } => match rank_a.cmp(rank_b) {
+                    Ordering::Equal => compare_extra(extra_a, extra_b),
                     ord => ord,
                 },
-                Self::Straight { .. } => Some(Ordering::Less),
-                Self::Flush { .. } => Some(Ordering::Less),
-                Self::FullHouse { .. } => Some(Ordering::Less),
-                Self::FourOfAKind { .. } => Some(Ordering::Less),
-                Self::StraightFlush { .. } => Some(Ordering::Less),
+                Self::Straight { .. } => Ordering::Less,
+                Self::Flush { .. } => Ordering::Less,
+                Self::FullHouse { .. } => Ordering::Less,
+                Self::FourOfAKind { .. } => Ordering::Less,
+                Self::StraightFlush { .. } => Ordering::Less,
+                Self::FiveOfAKind { .. } => Ordering::Less,
             },
             Self::Straight { rank: rank_a } => match other {
-                Self::HighCard { .. } => Some(Ordering::Greater),
-                Self::Pair { .. } => Some(Ordering::Greater),
-                Self::TwoPairs { .. } => Some(Ordering::Greater),
-                Self::ThreeOfAKind { .. } => Some(Ordering::Greater),
+                Self::HighCard { .. } => Ordering::Greater,
+                Self::Pair { .. } => Ordering::Greater,
+                Self::TwoPairs { .. } => Ordering::Greater,
+                Self::ThreeOfAKind { .. } => Ordering::Greater,
                 Self::Straight { rank: rank_b } => match rank_a {
                     Rank::Ace => match rank_b {
-                        Rank::Ace => Some(Ordering::Equal),
-                        _ => Some(Ordering::Less),
+                        Rank::Ace => Ordering::Equal,
+                        _ => Ordering::Less,
                     },
                     rank_a => match rank_b {
-                        Rank::Ace => Some(Ordering::Equal),
-                        rank_b => rank_a.partial_cmp(rank_b),
+                        Rank::Ace => Ordering::Greater,
+                        rank_b => rank_a.cmp(rank_b),
                     },
                 },
-                Self::Flush { .. } => Some(Ordering::Less),
-                Self::FullHouse { .. } => Some(Ordering::Less),
-                Self::FourOfAKind { .. } => Some(Ordering::Less),
-                Self::StraightFlush { .. } => Some(Ordering::Less),
+                Self::Flush { .. } => Ordering::Less,
+                Self::FullHouse { .. } => Ordering::Less,
+                Self::FourOfAKind { .. } => Ordering::Less,
+                Self::StraightFlush { .. } => Ordering::Less,
+                Self::FiveOfAKind { .. } => Ordering::Less,
             },
-            Self::Flush { rank: rank_a } => match other {
-                Self::HighCard { .. } => Some(Ordering::Greater),
-                Self::Pair { .. } => Some(Ordering::Greater),
-                Self::TwoPairs { .. } => Some(Ordering::Greater),
-                Self::ThreeOfAKind { .. } => Some(Ordering::Greater),
-                Self::Straight { .. } => Some(Ordering::Greater),
-                Self::Flush { rank: rank_b } => rank_a.partial_cmp(rank_b),
-                Self::FullHouse { .. } => Some(Ordering::Less),
-                Self::FourOfAKind { .. } => Some(Ordering::Less),
-                Self::StraightFlush { .. } => Some(Ordering::Less),
+            Self::Flush {
+                rank: rank_a,
+                extra: extra_a,
+            } => match other {
+                Self::HighCard { .. } => Ordering::Greater,
+                Self::Pair { .. } => Ordering::Greater,
+                Self::TwoPairs { .. } => Ordering::Greater,
+                Self::ThreeOfAKind { .. } => Ordering::Greater,
+                Self::Straight { .. } => Ordering::Greater,
+                Self::Flush {
+                    rank: rank_b,
+                    extra: extra_b,
+                } => match rank_a.cmp(rank_b) {
+                    Ordering::Equal => compare_extra(extra_a, extra_b),
+                    ord => ord,
+                },
+                Self::FullHouse { .. } => Ordering::Less,
+                Self::FourOfAKind { .. } => Ordering::Less,
+                Self::StraightFlush { .. } => Ordering::Less,
+                Self::FiveOfAKind { .. } => Ordering::Less,
             },
             Self::FullHouse {
                 two: rank_two_a,
                 three: rank_three_a,
             } => match other {
-                Self::HighCard { .. } => Some(Ordering::Greater),
-                Self::Pair { .. } => Some(Ordering::Greater),
-                Self::TwoPairs { .. } => Some(Ordering::Greater),
-                Self::ThreeOfAKind { .. } => Some(Ordering::Greater),
-                Self::Straight { .. } => Some(Ordering::Greater),
-                Self::Flush { .. } => Some(Ordering::Greater),
+                Self::HighCard { .. } => Ordering::Greater,
+                Self::Pair { .. } => Ordering::Greater,
+                Self::TwoPairs { .. } => Ordering::Greater,
+                Self::ThreeOfAKind { .. } => Ordering::Greater,
+                Self::Straight { .. } => Ordering::Greater,
+                Self::Flush { .. } => Ordering::Greater,
                 Self::FullHouse {
                     two: rank_two_b,
                     three: rank_three_b,
-                } => match rank_three_a.partial_cmp(rank_three_b) {
-                    Some(Ordering::Equal) => rank_two_a.partial_cmp(rank_two_b),
+                } => match rank_three_a.cmp(rank_three_b) {
+                    Ordering::Equal => rank_two_a.cmp(rank_two_b),
                     ord => ord,
                 },
-                Self::FourOfAKind { .. } => Some(Ordering::Less),
-                Self::StraightFlush { .. } => Some(Ordering::Less),
+                Self::FourOfAKind { .. } => Ordering::Less,
+                Self::StraightFlush { .. } => Ordering::Less,
+                Self::FiveOfAKind { .. } => Ordering::Less,
             },
             Self::FourOfAKind {
                 rank: rank_a,
                 extra: extra_a,
             } => match other {
-                Self::HighCard { .. } => Some(Ordering::Greater),
-                Self::Pair { .. } => Some(Ordering::Greater),
-                Self::TwoPairs { .. } => Some(Ordering::Greater),
-                Self::ThreeOfAKind { .. } => Some(Ordering::Greater),
-                Self::Straight { .. } => Some(Ordering::Greater),
-                Self::Flush { .. } => Some(Ordering::Greater),
-                Self::FullHouse { .. } => Some(Ordering::Greater),
+                Self::HighCard { .. } => Ordering::Greater,
+                Self::Pair { .. } => Ordering::Greater,
+                Self::TwoPairs { .. } => Ordering::Greater,
+                Self::ThreeOfAKind { .. } => Ordering::Greater,
+                Self::Straight { .. } => Ordering::Greater,
+                Self::Flush { .. } => Ordering::Greater,
+                Self::FullHouse { .. } => Ordering::Greater,
                 Self::FourOfAKind {
                     rank: rank_b,
                     extra: extra_b,
-                } => match rank_a.partial_cmp(rank_b) {
-                    Some(Ordering::Equal) => Some(compare_extra(extra_a, extra_b)),
+                } => match rank_a.cmp(rank_b) {
+                    Ordering::Equal => compare_extra(extra_a, extra_b),
                     ord => ord,
                 },
-                Self::StraightFlush { .. } => Some(Ordering::Less),
+                Self::StraightFlush { .. } => Ordering::Less,
+                Self::FiveOfAKind { .. } => Ordering::Less,
             },
             Self::StraightFlush { rank: rank_a } => match other {
-                Self::HighCard { .. } => Some(Ordering::Greater),
-                Self::Pair { .. } => Some(Ordering::Greater),
-                Self::TwoPairs { .. } => Some(Ordering::Greater),
-                Self::ThreeOfAKind { .. } => Some(Ordering::Greater),
-                Self::Straight { .. } => Some(Ordering::Greater),
-                Self::Flush { .. } => Some(Ordering::Greater),
-                Self::FullHouse { .. } => Some(Ordering::Greater),
-                Self::FourOfAKind { .. } => Some(Ordering::Greater),
+                Self::HighCard { .. } => Ordering::Greater,
+                Self::Pair { .. } => Ordering::Greater,
+                Self::TwoPairs { .. } => Ordering::Greater,
+                Self::ThreeOfAKind { .. } => Ordering::Greater,
+                Self::Straight { .. } => Ordering::Greater,
+                Self::Flush { .. } => Ordering::Greater,
+                Self::FullHouse { .. } => Ordering::Greater,
+                Self::FourOfAKind { .. } => Ordering::Greater,
                 Self::StraightFlush { rank: rank_b } => match rank_a {
                     Rank::Ace => match rank_b {
-                        Rank::Ace => Some(Ordering::Equal),
-                        _ => Some(Ordering::Less),
+                        Rank::Ace => Ordering::Equal,
+                        _ => Ordering::Less,
                     },
                     rank_a => match rank_b {
-                        Rank::Ace => Some(Ordering::Equal),
-                        rank_b => rank_a.partial_cmp(rank_b),
+                        Rank::Ace => Ordering::Greater,
+                        rank_b => rank_a.cmp(rank_b),
                     },
                 },
+                Self::FiveOfAKind { .. } => Ordering::Less,
+            },
+            Self::FiveOfAKind { rank: rank_a } => match other {
+                Self::FiveOfAKind { rank: rank_b } => rank_a.cmp(rank_b),
+                _ => Ordering::Greater,
             },
         }
     }
 }
 
-impl Ord for Combination {
-    fn cmp(&self, other: &Self) -> Ordering {
-        self.partial_cmp(&other).unwrap()
-    }
-}
-
 fn compare_extra(xs: &[Rank], ys: &[Rank]) -> Ordering {
     assert_eq!(xs.len(), ys.len());
 
@@ -228,10 +259,25 @@ mod tests {
 
     #[test]
     fn test_ordering_high_card() {
-        let lhs = Combination::HighCard { rank: Rank::Two };
+        let lhs = Combination::HighCard {
+            rank: Rank::Two,
+            extra: [Rank::Three, Rank::Four, Rank::Five, Rank::Six],
+        };
 
-        assert_eq!(lhs, Combination::HighCard { rank: Rank::Two });
-        assert_lt!(lhs, Combination::HighCard { rank: Rank::Three });
+        assert_eq!(
+            lhs,
+            Combination::HighCard {
+                rank: Rank::Two,
+                extra: [Rank::Three, Rank::Four, Rank::Five, Rank::Six]
+            }
+        );
+        assert_lt!(
+            lhs,
+            Combination::HighCard {
+                rank: Rank::Three,
+                extra: [Rank::Two, Rank::Four, Rank::Five, Rank::Six]
+            }
+        );
         assert_lt!(
             lhs,
             Combination::Pair {
@@ -255,7 +301,13 @@ mod tests {
             }
         );
         assert_lt!(lhs, Combination::Straight { rank: Rank::Ace });
-        assert_lt!(lhs, Combination::Flush { rank: Rank::Ace });
+        assert_lt!(
+            lhs,
+            Combination::Flush {
+                rank: Rank::Ace,
+                extra: [Rank::Two, Rank::Three, Rank::Four, Rank::Five]
+            }
+        );
         assert_lt!(
             lhs,
             Combination::FullHouse {
@@ -280,7 +332,13 @@ mod tests {
             extra: [Rank::Eight, Rank::Six, Rank::Three],
         };
 
-        assert_gt!(lhs, Combination::HighCard { rank: Rank::Two });
+        assert_gt!(
+            lhs,
+            Combination::HighCard {
+                rank: Rank::Two,
+                extra: [Rank::Three, Rank::Four, Rank::Five, Rank::Six]
+            }
+        );
         // assert_eq!(
         //     lhs,
         //     Combination::Pair {
@@ -325,7 +383,13 @@ mod tests {
             }
         );
         assert_lt!(lhs, Combination::Straight { rank: Rank::Ace });
-        assert_lt!(lhs, Combination::Flush { rank: Rank::Ace });
+        assert_lt!(
+            lhs,
+            Combination::Flush {
+                rank: Rank::Ace,
+                extra: [Rank::Two, Rank::Three, Rank::Four, Rank::Five]
+            }
+        );
         assert_lt!(
             lhs,
             Combination::FullHouse {
@@ -351,7 +415,13 @@ mod tests {
             extra: [Rank::Four],
         };
 
-        assert_gt!(lhs, Combination::HighCard { rank: Rank::Two });
+        assert_gt!(
+            lhs,
+            Combination::HighCard {
+                rank: Rank::Two,
+                extra: [Rank::Three, Rank::Four, Rank::Five, Rank::Six]
+            }
+        );
         assert_gt!(
             lhs,
             Combination::Pair {
@@ -399,7 +469,13 @@ mod tests {
             }
         );
         assert_lt!(lhs, Combination::Straight { rank: Rank::Ace });
-        assert_lt!(lhs, Combination::Flush { rank: Rank::Ace });
+        assert_lt!(
+            lhs,
+            Combination::Flush {
+                rank: Rank::Ace,
+                extra: [Rank::Two, Rank::Three, Rank::Four, Rank::Five]
+            }
+        );
         assert_lt!(
             lhs,
             Combination::FullHouse {
@@ -424,7 +500,13 @@ mod tests {
             extra: [Rank::Three, Rank::Four],
         };
 
-        assert_gt!(lhs, Combination::HighCard { rank: Rank::Two });
+        assert_gt!(
+            lhs,
+            Combination::HighCard {
+                rank: Rank::Two,
+                extra: [Rank::Three, Rank::Four, Rank::Five, Rank::Six]
+            }
+        );
         assert_gt!(
             lhs,
             Combination::Pair {
@@ -478,7 +560,13 @@ mod tests {
         );
 
         assert_lt!(lhs, Combination::Straight { rank: Rank::Ace });
-        assert_lt!(lhs, Combination::Flush { rank: Rank::Ace });
+        assert_lt!(
+            lhs,
+            Combination::Flush {
+                rank: Rank::Ace,
+                extra: [Rank::Two, Rank::Three, Rank::Four, Rank::Five]
+            }
+        );
         assert_lt!(
             lhs,
             Combination::FullHouse {
@@ -500,7 +588,13 @@ mod tests {
     fn test_ordering_straight() {
         let lhs = Combination::Straight { rank: Rank::Ace };
 
-        assert_gt!(lhs, Combination::HighCard { rank: Rank::Two });
+        assert_gt!(
+            lhs,
+            Combination::HighCard {
+                rank: Rank::Two,
+                extra: [Rank::Three, Rank::Four, Rank::Five, Rank::Six]
+            }
+        );
         assert_gt!(
             lhs,
             Combination::Pair {
@@ -525,7 +619,13 @@ mod tests {
         );
         assert_eq!(lhs, Combination::Straight { rank: Rank::Ace });
         assert_lt!(lhs, Combination::Straight { rank: Rank::Two });
-        assert_lt!(lhs, Combination::Flush { rank: Rank::Ace });
+        assert_lt!(
+            lhs,
+            Combination::Flush {
+                rank: Rank::Ace,
+                extra: [Rank::Two, Rank::Three, Rank::Four, Rank::Five]
+            }
+        );
         assert_lt!(
             lhs,
             Combination::FullHouse {
@@ -545,9 +645,18 @@ mod tests {
 
     #[test]
     fn test_ordering_flush() {
-        let lhs = Combination::Flush { rank: Rank::Ace };
+        let lhs = Combination::Flush {
+            rank: Rank::Ace,
+            extra: [Rank::Two, Rank::Three, Rank::Four, Rank::Five],
+        };
 
-        assert_gt!(lhs, Combination::HighCard { rank: Rank::Two });
+        assert_gt!(
+            lhs,
+            Combination::HighCard {
+                rank: Rank::Two,
+                extra: [Rank::Three, Rank::Four, Rank::Five, Rank::Six]
+            }
+        );
         assert_gt!(
             lhs,
             Combination::Pair {
@@ -571,8 +680,20 @@ mod tests {
             }
         );
         assert_gt!(lhs, Combination::Straight { rank: Rank::Ace });
-        assert_eq!(lhs, Combination::Flush { rank: Rank::Ace });
-        assert_gt!(lhs, Combination::Flush { rank: Rank::Two });
+        assert_eq!(
+            lhs,
+            Combination::Flush {
+                rank: Rank::Ace,
+                extra: [Rank::Two, Rank::Three, Rank::Four, Rank::Five]
+            }
+        );
+        assert_gt!(
+            lhs,
+            Combination::Flush {
+                rank: Rank::Two,
+                extra: [Rank::Three, Rank::Four, Rank::Five, Rank::Six]
+            }
+        );
         assert_lt!(
             lhs,
             Combination::FullHouse {
@@ -597,7 +718,13 @@ mod tests {
             three: Rank::Three,
         };
 
-        assert_gt!(lhs, Combination::HighCard { rank: Rank::Two });
+        assert_gt!(
+            lhs,
+            Combination::HighCard {
+                rank: Rank::Two,
+                extra: [Rank::Three, Rank::Four, Rank::Five, Rank::Six]
+            }
+        );
         assert_gt!(
             lhs,
             Combination::Pair {
@@ -621,7 +748,13 @@ mod tests {
             }
         );
         assert_gt!(lhs, Combination::Straight { rank: Rank::Ace });
-        assert_gt!(lhs, Combination::Flush { rank: Rank::Ace });
+        assert_gt!(
+            lhs,
+            Combination::Flush {
+                rank: Rank::Ace,
+                extra: [Rank::Two, Rank::Three, Rank::Four, Rank::Five]
+            }
+        );
         assert_eq!(
             lhs,
             Combination::FullHouse {
@@ -660,7 +793,13 @@ mod tests {
             extra: [Rank::Three],
         };
 
-        assert_gt!(lhs, Combination::HighCard { rank: Rank::Two });
+        assert_gt!(
+            lhs,
+            Combination::HighCard {
+                rank: Rank::Two,
+                extra: [Rank::Three, Rank::Four, Rank::Five, Rank::Six]
+            }
+        );
         assert_gt!(
             lhs,
             Combination::Pair {
@@ -684,7 +823,13 @@ mod tests {
             }
         );
         assert_gt!(lhs, Combination::Straight { rank: Rank::Ace });
-        assert_gt!(lhs, Combination::Flush { rank: Rank::Ace });
+        assert_gt!(
+            lhs,
+            Combination::Flush {
+                rank: Rank::Ace,
+                extra: [Rank::Two, Rank::Three, Rank::Four, Rank::Five]
+            }
+        );
         assert_gt!(
             lhs,
             Combination::FullHouse {
@@ -720,7 +865,13 @@ mod tests {
     fn test_ordering_straight_flush() {
         let lhs = Combination::StraightFlush { rank: Rank::Ace };
 
-        assert_gt!(lhs, Combination::HighCard { rank: Rank::Two });
+        assert_gt!(
+            lhs,
+            Combination::HighCard {
+                rank: Rank::Two,
+                extra: [Rank::Three, Rank::Four, Rank::Five, Rank::Six]
+            }
+        );
         assert_gt!(
             lhs,
             Combination::Pair {
@@ -744,7 +895,13 @@ mod tests {
             }
         );
         assert_gt!(lhs, Combination::Straight { rank: Rank::Ace });
-        assert_gt!(lhs, Combination::Flush { rank: Rank::Ace });
+        assert_gt!(
+            lhs,
+            Combination::Flush {
+                rank: Rank::Ace,
+                extra: [Rank::Two, Rank::Three, Rank::Four, Rank::Five]
+            }
+        );
         assert_gt!(
             lhs,
             Combination::FullHouse {
@@ -762,4 +919,58 @@ mod tests {
         assert_eq!(lhs, Combination::StraightFlush { rank: Rank::Ace });
         assert_lt!(lhs, Combination::StraightFlush { rank: Rank::Two });
     }
+
+    #[test]
+    fn test_wheel_ordering() {
+        // Straights are keyed by their lowest card, the ace for the wheel.
+        let wheel = Combination::Straight { rank: Rank::Ace };
+        let six_high = Combination::Straight { rank: Rank::Two };
+        assert_lt!(wheel, six_high);
+        assert_gt!(six_high, wheel);
+
+        let steel_wheel = Combination::StraightFlush { rank: Rank::Ace };
+        let six_high = Combination::StraightFlush { rank: Rank::Two };
+        assert_lt!(steel_wheel, six_high);
+        assert_gt!(six_high, steel_wheel);
+    }
+
+    #[test]
+    fn test_high_card_kickers() {
+        let lhs = Combination::HighCard {
+            rank: Rank::Ace,
+            extra: [Rank::King, Rank::Queen, Rank::Jack, Rank::Seven],
+        };
+        let rhs = Combination::HighCard {
+            rank: Rank::Ace,
+            extra: [Rank::Queen, Rank::Jack, Rank::Ten, Rank::Seven],
+        };
+        assert_gt!(lhs, rhs);
+
+        let rhs = Combination::HighCard {
+            rank: Rank::Ace,
+            extra: [Rank::King, Rank::Queen, Rank::Jack, Rank::Six],
+        };
+        assert_gt!(lhs, rhs);
+        assert_eq!(lhs.cmp(&lhs), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_flush_kickers() {
+        let lhs = Combination::Flush {
+            rank: Rank::Ace,
+            extra: [Rank::King, Rank::Nine, Rank::Eight, Rank::Seven],
+        };
+        let rhs = Combination::Flush {
+            rank: Rank::Ace,
+            extra: [Rank::King, Rank::Nine, Rank::Eight, Rank::Six],
+        };
+        assert_gt!(lhs, rhs);
+        assert_gt!(
+            rhs,
+            Combination::Flush {
+                rank: Rank::Ace,
+                extra: [Rank::Queen, Rank::Jack, Rank::Nine, Rank::Two],
+            }
+        );
+    }
 }
@@ -0,0 +1,106 @@
+use std::convert::TryInto;
+
+use ::itertools::Itertools;
+
+use crate::card::wild::WildCard;
+use crate::card::Card;
+use crate::card::Rank;
+use crate::card::Suit;
+use crate::combination::Combination;
+use crate::game::Variant;
+
+impl Combination {
+    /// The best combination the wild cards can make, jokers and cards of
+    /// `wild_ranks` standing for any card, including one already in hand.
+    pub fn from_wild_cards(cards: [WildCard; 5], wild_ranks: &[Rank]) -> Self {
+        let naturals = cards
+            .iter()
+            .filter(|card| !card.is_wild(wild_ranks))
+            .map(|card| match card {
+                WildCard::Natural(card) => *card,
+                WildCard::Joker => unreachable!(),
+            })
+            .collect::<Vec<_>>();
+        let wilds = 5 - naturals.len();
+
+        // Wild cards all take the suit of the naturals: that makes a flush
+        // whenever one is possible, and suits matter for nothing else.
+        let suit = naturals.first().map_or(Suit::Spades, |card| card.1);
+
+        Rank::ALL
+            .iter()
+            .combinations_with_replacement(wilds)
+            .map(|ranks| {
+                let mut cards = naturals.clone();
+                cards.extend(ranks.into_iter().map(|rank| Card(*rank, suit)));
+
+                Self::from_variant(Variant(cards.try_into().unwrap()))
+            })
+            .max()
+            .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryInto;
+
+    use crate::card::wild::parse_wild_cards;
+    use crate::card::Rank;
+    use crate::combination::Combination;
+
+    fn best(cards: &str, wild_ranks: &[Rank]) -> Combination {
+        Combination::from_wild_cards(parse_wild_cards(cards).try_into().unwrap(), wild_ranks)
+    }
+
+    #[test]
+    fn test_from_wild_cards() {
+        assert_eq!(
+            best("AhAdAcAsJk", &[]),
+            Combination::FiveOfAKind { rank: Rank::Ace }
+        );
+        assert_eq!(
+            best("AhKhQhJhJk", &[]),
+            Combination::StraightFlush { rank: Rank::Ten }
+        );
+        assert_eq!(
+            best("9h2c7h2d5h", &[Rank::Two]),
+            Combination::StraightFlush { rank: Rank::Five }
+        );
+        assert_eq!(
+            best("9h2c7h2d5d", &[Rank::Two]),
+            Combination::Straight { rank: Rank::Five }
+        );
+        assert_eq!(
+            best("9h2c9d2d5h", &[Rank::Two]),
+            Combination::FourOfAKind {
+                rank: Rank::Nine,
+                extra: [Rank::Five],
+            }
+        );
+        assert_eq!(
+            best("2c2d2h2sJk", &[Rank::Two]),
+            Combination::FiveOfAKind { rank: Rank::Ace }
+        );
+        assert_eq!(
+            best("AhKd7c4s3s", &[Rank::Two]),
+            Combination::HighCard {
+                rank: Rank::Ace,
+                extra: [Rank::King, Rank::Seven, Rank::Four, Rank::Three]
+            }
+        );
+    }
+
+    #[test]
+    fn test_five_of_a_kind_ranks_highest() {
+        assert!(
+            Combination::FiveOfAKind { rank: Rank::Two }
+                > Combination::StraightFlush { rank: Rank::Ten }
+        );
+        assert!(
+            Combination::FiveOfAKind { rank: Rank::Three }
+                > Combination::FiveOfAKind { rank: Rank::Two }
+        );
+        assert_eq!(best("KhKdJkKcKs", &[]).to_string(), "five of a kind, kings");
+    }
+}
@@ -1,14 +1,25 @@
 mod impl_combination;
+mod impl_display;
 mod impl_eq;
 mod impl_hash;
 mod impl_ord;
+mod impl_wild;
+
+pub(crate) use impl_display::name;
+pub(crate) use impl_display::plural;
 
 use crate::card::Rank;
 
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(
+    feature = "serde",
+    derive(::serde::Serialize, ::serde::Deserialize),
+    serde(tag = "category", rename_all = "snake_case")
+)]
 pub enum Combination {
     HighCard {
         rank: Rank,
+        extra: [Rank; 4],
     },
     Pair {
         rank: Rank,
@@ -28,6 +39,7 @@ pub enum Combination {
     },
     Flush {
         rank: Rank,
+        extra: [Rank; 4],
     },
     FullHouse {
         two: Rank,
@@ -40,4 +52,8 @@ pub enum Combination {
     StraightFlush {
         rank: Rank,
     },
+    /// Only possible with wild cards.
+    FiveOfAKind {
+        rank: Rank,
+    },
 }
@@ -0,0 +1,222 @@
+use std::convert::TryInto;
+
+use ::rand::Rng;
+use ::rand::SeedableRng;
+use ::rand_chacha::ChaCha8Rng;
+
+use crate::card::Card;
+use crate::game::Board;
+use crate::game::Game;
+use crate::game::HandOf2;
+use crate::game::HandOf3;
+use crate::game::HandOf4;
+use crate::game::HandOf5;
+use crate::game::Kind;
+
+/// Cards left to deal; the last card is the top of the deck.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Deck(Vec<Card>);
+
+impl Deck {
+    pub fn new() -> Self {
+        Self(crate::card::all_cards())
+    }
+
+    /// A fresh deck shuffled with ChaCha8, identical for the same seed
+    /// on every platform.
+    pub fn seeded(seed: u64) -> Self {
+        let mut deck = Self::new();
+        deck.shuffle(&mut ChaCha8Rng::seed_from_u64(seed));
+
+        deck
+    }
+
+    pub fn cards(&self) -> &[Card] {
+        &self.0
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn remove(&mut self, cards: &[Card]) {
+        self.0.retain(|card| !cards.contains(card));
+    }
+
+    /// Fisher–Yates shuffle. Indices are drawn as `u32`, since `rand`
+    /// samples `usize` ranges differently on 32-bit and 64-bit targets.
+    pub fn shuffle<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        for i in (1..self.0.len()).rev() {
+            let j = rng.gen_range(0..=i as u32) as usize;
+            self.0.swap(i, j);
+        }
+    }
+
+    pub fn deal(&mut self, n: usize) -> Vec<Card> {
+        assert!(n <= self.0.len(), "not enough cards in deck");
+
+        let mut cards = self.0.split_off(self.0.len() - n);
+        cards.reverse();
+
+        cards
+    }
+
+    pub fn deal_board(&mut self, n: usize) -> Board {
+        assert!((3..=5).contains(&n), "unexpected board size: {}", n);
+
+        Board(self.deal(n))
+    }
+
+    pub fn deal_hand_of_2(&mut self) -> HandOf2 {
+        HandOf2(self.deal(2).try_into().unwrap())
+    }
+
+    pub fn deal_hand_of_3(&mut self) -> HandOf3 {
+        HandOf3(self.deal(3).try_into().unwrap())
+    }
+
+    pub fn deal_hand_of_4(&mut self) -> HandOf4 {
+        HandOf4(self.deal(4).try_into().unwrap())
+    }
+
+    pub fn deal_hand_of_5(&mut self) -> HandOf5 {
+        HandOf5(self.deal(5).try_into().unwrap())
+    }
+
+    /// Deals hole cards to every player first and then a complete board, or
+    /// the dealer's hand in three-card poker.
+    pub fn deal_game(&mut self, kind: Kind, players: usize) -> Game {
+        match kind {
+            Kind::TexasHoldem => {
+                let hands = (0..players).map(|_| self.deal_hand_of_2().into()).collect();
+                Game::TexasHoldem(self.deal_board(5), hands)
+            }
+            Kind::OmahaHoldem => {
+                let hands = (0..players).map(|_| self.deal_hand_of_4().into()).collect();
+                Game::OmahaHoldem(self.deal_board(5), hands)
+            }
+            Kind::FiveCardDraw => {
+                let hands = (0..players).map(|_| self.deal_hand_of_5().into()).collect();
+                Game::FiveCardDraw(hands)
+            }
+            Kind::ThreeCardPoker => {
+                let hands = (0..players).map(|_| self.deal_hand_of_3().into()).collect();
+                Game::ThreeCardPoker(self.deal_hand_of_3(), hands)
+            }
+        }
+    }
+}
+
+impl Default for Deck {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::itertools::Itertools;
+    use ::rand::rngs::mock::StepRng;
+
+    use crate::card::Card;
+    use crate::card::Rank;
+    use crate::card::Suit;
+    use crate::deck::Deck;
+    use crate::game::Game;
+    use crate::game::Kind;
+
+    #[test]
+    fn test_new() {
+        let deck = Deck::new();
+
+        assert_eq!(deck.len(), 52);
+        assert_eq!(deck.cards().iter().unique().count(), 52);
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut deck = Deck::new();
+        deck.remove(&crate::card::parse_cards("AdKs"));
+
+        assert_eq!(deck.len(), 50);
+        assert!(!deck.cards().contains(&Card(Rank::Ace, Suit::Diamonds)));
+        assert!(!deck.cards().contains(&Card(Rank::King, Suit::Spades)));
+    }
+
+    #[test]
+    fn test_shuffle() {
+        let mut deck = Deck::new();
+        deck.shuffle(&mut StepRng::new(0, 1));
+
+        assert_eq!(deck.len(), 52);
+        assert_eq!(deck.cards().iter().unique().count(), 52);
+        assert_ne!(deck, Deck::new());
+    }
+
+    #[test]
+    fn test_seeded() {
+        assert_eq!(Deck::seeded(42), Deck::seeded(42));
+        assert_ne!(Deck::seeded(42), Deck::seeded(43));
+
+        // Pinned so that a change of the shuffle, or of the platform, shows.
+        assert_eq!(
+            Deck::seeded(42).deal(5),
+            crate::card::parse_cards("4sTh3sKhJc")
+        );
+    }
+
+    #[test]
+    fn test_deal() {
+        let mut deck = Deck::new();
+
+        assert_eq!(
+            deck.deal(2),
+            vec![Card(Rank::Ace, Suit::Spades), Card(Rank::Ace, Suit::Hearts)]
+        );
+        assert_eq!(deck.len(), 50);
+        assert_eq!(deck.deal_board(3).cards().len(), 3);
+        assert_eq!(deck.len(), 47);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_deal_too_many() {
+        Deck::new().deal(53);
+    }
+
+    #[test]
+    fn test_deal_game() {
+        let mut deck = Deck::seeded(7);
+
+        let game = deck.deal_game(Kind::OmahaHoldem, 6);
+        assert_eq!(game.kind(), Kind::OmahaHoldem);
+        assert_eq!(deck.len(), 52 - 6 * 4 - 5);
+
+        match game {
+            Game::OmahaHoldem(board, hands) => {
+                let cards = board
+                    .cards()
+                    .iter()
+                    .chain(hands.iter().flat_map(|named| named.hand.cards().iter()))
+                    .collect::<Vec<_>>();
+
+                assert_eq!(cards.len(), 29);
+                assert_eq!(cards.iter().unique().count(), 29);
+            }
+            _ => panic!("unexpected game"),
+        }
+
+        let game = Deck::seeded(7).deal_game(Kind::FiveCardDraw, 10);
+        assert_eq!(Game::rank_hands(game).len(), 10);
+
+        let mut deck = Deck::seeded(7);
+        let game = deck.deal_game(Kind::ThreeCardPoker, 4);
+        assert_eq!(game.kind(), Kind::ThreeCardPoker);
+        assert_eq!(deck.len(), 52 - 4 * 3 - 3);
+        assert_eq!(Game::rank_hands(game).len(), 4);
+    }
+}
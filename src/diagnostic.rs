@@ -0,0 +1,93 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+/// Where in a line parsing failed, carried inside the `io::Error` parsers
+/// return so callers can point at the offending token.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// 1-based, in characters.
+    pub column: usize,
+    pub token: String,
+    pub message: String,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for Diagnostic {}
+
+pub fn error(column: usize, token: &str, message: impl ToString) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        Diagnostic {
+            column,
+            token: token.to_string(),
+            message: message.to_string(),
+        },
+    )
+}
+
+/// The diagnostic inside `error`, if the parser recorded one.
+pub fn of(error: &io::Error) -> Option<&Diagnostic> {
+    error.get_ref()?.downcast_ref::<Diagnostic>()
+}
+
+/// Splits `line` on whitespace, keeping the column of every token.
+pub fn tokens(line: &str) -> Vec<(usize, &str)> {
+    let mut tokens = vec![];
+    let mut start = None;
+
+    for (column, (i, c)) in line.char_indices().enumerate() {
+        match (start, c.is_whitespace()) {
+            (None, false) => start = Some((column + 1, i)),
+            (Some((column, begin)), true) => {
+                tokens.push((column, &line[begin..i]));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+
+    if let Some((column, begin)) = start {
+        tokens.push((column, &line[begin..]));
+    }
+
+    tokens
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::diagnostic::error;
+    use crate::diagnostic::of;
+    use crate::diagnostic::tokens;
+    use crate::diagnostic::Diagnostic;
+
+    #[test]
+    fn test_tokens() {
+        assert_eq!(
+            tokens("  texas-holdem  A♥K♦ 2c"),
+            vec![(3, "texas-holdem"), (17, "A♥K♦"), (22, "2c")]
+        );
+        assert_eq!(tokens(" "), vec![]);
+    }
+
+    #[test]
+    fn test_of() {
+        let diagnosed = error(5, "AxKd", "invalid hand: AxKd");
+
+        assert_eq!(diagnosed.to_string(), "invalid hand: AxKd");
+        assert_eq!(
+            of(&diagnosed),
+            Some(&Diagnostic {
+                column: 5,
+                token: "AxKd".to_string(),
+                message: "invalid hand: AxKd".to_string(),
+            })
+        );
+        assert_eq!(of(&std::io::Error::other("plain")), None);
+    }
+}
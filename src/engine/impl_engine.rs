@@ -0,0 +1,652 @@
+use std::io;
+
+use ::itertools::Itertools;
+
+use crate::card::Card;
+use crate::engine::Action;
+use crate::engine::Engine;
+use crate::engine::LegalAction;
+use crate::engine::Limit;
+use crate::engine::Seat;
+use crate::engine::Stakes;
+use crate::engine::Street;
+use crate::game::Board;
+use crate::game::Game;
+use crate::game::HandOf2;
+use crate::game::Named;
+use crate::payout::OddChip;
+use crate::payout::Player;
+
+const FIXED_LIMIT_CAP: u64 = 4;
+
+impl Engine {
+    /// Seats are listed clockwise. Antes and blinds are posted right away;
+    /// heads-up the button posts the small blind.
+    pub fn new(stakes: Stakes, stacks: &[u64], button: usize) -> Self {
+        assert!(stacks.len() >= 2, "not enough players");
+        assert!(button < stacks.len(), "button out of range");
+
+        let n = stacks.len();
+
+        let mut engine = Self {
+            stakes,
+            seats: stacks
+                .iter()
+                .map(|stack| Seat {
+                    stack: *stack,
+                    committed: 0,
+                    contributed: 0,
+                    folded: false,
+                    hand: None,
+                })
+                .collect(),
+            button,
+            street: Street::Preflop,
+            board: vec![],
+            to_act: None,
+            current_bet: 0,
+            last_raise: stakes.big_blind,
+            bets: 1,
+            acted: vec![false; n],
+            raise_closed: vec![false; n],
+        };
+
+        for seat in engine.seats.iter_mut() {
+            let ante = stakes.ante.min(seat.stack);
+            seat.stack -= ante;
+            seat.contributed += ante;
+        }
+
+        let (small_blind, big_blind) = if n == 2 {
+            (button, (button + 1) % n)
+        } else {
+            ((button + 1) % n, (button + 2) % n)
+        };
+
+        engine.post(small_blind, stakes.small_blind);
+        engine.post(big_blind, stakes.big_blind);
+        engine.current_bet = stakes.big_blind;
+        engine.to_act = engine.next_to_act(big_blind);
+
+        engine
+    }
+
+    pub fn stakes(&self) -> Stakes {
+        self.stakes
+    }
+
+    pub fn seats(&self) -> &[Seat] {
+        &self.seats
+    }
+
+    pub fn button(&self) -> usize {
+        self.button
+    }
+
+    pub fn street(&self) -> Street {
+        self.street
+    }
+
+    pub fn board(&self) -> &[Card] {
+        &self.board
+    }
+
+    pub fn to_act(&self) -> Option<usize> {
+        self.to_act
+    }
+
+    pub fn pot(&self) -> u64 {
+        self.seats.iter().map(|seat| seat.contributed).sum()
+    }
+
+    /// Seats that have not folded.
+    pub fn remaining(&self) -> Vec<usize> {
+        (0..self.seats.len())
+            .filter(|i| !self.seats[*i].folded)
+            .collect()
+    }
+
+    /// True once everybody but one player folded or the river betting is over.
+    pub fn is_finished(&self) -> bool {
+        self.remaining().len() == 1 || (self.street == Street::River && self.to_act.is_none())
+    }
+
+    pub fn set_hole_cards(&mut self, seat: usize, hand: HandOf2) -> Result<(), io::Error> {
+        let known = self.known_cards();
+
+        if hand.0.iter().any(|card| known.contains(card)) {
+            return Err(invalid("hole cards are already dealt"));
+        }
+
+        self.seats[seat].hand = Some(hand);
+
+        Ok(())
+    }
+
+    /// Deals the flop, turn or river once the previous betting round is over.
+    pub fn deal(&mut self, cards: &[Card]) -> Result<(), io::Error> {
+        if self.to_act.is_some() || self.is_finished() {
+            return Err(invalid("betting round is not over"));
+        }
+
+        let (street, n) = match self.street {
+            Street::Preflop => (Street::Flop, 3),
+            Street::Flop => (Street::Turn, 1),
+            Street::Turn => (Street::River, 1),
+            Street::River => return Err(invalid("board is complete")),
+        };
+
+        if cards.len() != n {
+            return Err(invalid("unexpected number of board cards"));
+        }
+
+        let known = self.known_cards();
+
+        if cards.iter().any(|card| known.contains(card)) || cards.iter().unique().count() != n {
+            return Err(invalid("board cards are already dealt"));
+        }
+
+        self.board.extend_from_slice(cards);
+        self.street = street;
+
+        for seat in self.seats.iter_mut() {
+            seat.committed = 0;
+        }
+
+        self.current_bet = 0;
+        self.last_raise = self.stakes.big_blind;
+        self.bets = 0;
+        self.acted = vec![false; self.seats.len()];
+        self.raise_closed = vec![false; self.seats.len()];
+        self.to_act = self.next_to_act(self.button);
+
+        Ok(())
+    }
+
+    pub fn legal_actions(&self) -> Vec<LegalAction> {
+        let i = match self.to_act {
+            Some(i) => i,
+            None => return vec![],
+        };
+
+        let seat = &self.seats[i];
+        let to_call = self.current_bet - seat.committed;
+
+        let mut actions = vec![LegalAction::Fold];
+
+        if to_call == 0 {
+            actions.push(LegalAction::Check);
+        } else {
+            actions.push(LegalAction::Call(to_call.min(seat.stack)));
+        }
+
+        if let Some((min, max)) = self.raise_range(i) {
+            if self.current_bet == 0 {
+                actions.push(LegalAction::Bet { min, max });
+            } else {
+                actions.push(LegalAction::Raise { min, max });
+            }
+        }
+
+        actions
+    }
+
+    pub fn act(&mut self, action: Action) -> Result<(), io::Error> {
+        let i = self.to_act.ok_or_else(|| invalid("no player to act"))?;
+        let to_call = self.current_bet - self.seats[i].committed;
+
+        match action {
+            Action::Fold => {
+                self.seats[i].folded = true;
+            }
+            Action::Check => {
+                if to_call != 0 {
+                    return Err(invalid("cannot check facing a bet"));
+                }
+            }
+            Action::Call => {
+                if to_call == 0 {
+                    return Err(invalid("nothing to call"));
+                }
+
+                self.post(i, to_call);
+            }
+            Action::Bet(to) | Action::Raise(to) => {
+                match (action, self.current_bet) {
+                    (Action::Bet(_), 0) => {}
+                    (Action::Raise(_), bet) if bet > 0 => {}
+                    (Action::Bet(_), _) => return Err(invalid("cannot bet facing a bet")),
+                    _ => return Err(invalid("nothing to raise")),
+                }
+
+                let (min, max) = self
+                    .raise_range(i)
+                    .ok_or_else(|| invalid("raising is not allowed"))?;
+
+                if to < min || to > max {
+                    return Err(invalid("bet size out of range"));
+                }
+
+                let increment = to - self.current_bet;
+                let full = increment >= self.last_raise;
+
+                for j in (0..self.seats.len()).filter(|j| *j != i) {
+                    if !full && self.acted[j] {
+                        self.raise_closed[j] = true;
+                    } else if full {
+                        self.raise_closed[j] = false;
+                    }
+
+                    self.acted[j] = false;
+                }
+
+                if full {
+                    self.last_raise = increment;
+                }
+
+                self.post(i, to - self.seats[i].committed);
+                self.current_bet = to;
+                self.bets += 1;
+            }
+        }
+
+        self.acted[i] = true;
+        self.to_act = if self.remaining().len() == 1 {
+            None
+        } else {
+            self.next_to_act(i)
+        };
+
+        Ok(())
+    }
+
+    /// Groups of seats from the best hand to the worst.
+    pub fn showdown(&self) -> Result<Vec<Vec<usize>>, io::Error> {
+        if !self.is_finished() || self.remaining().len() == 1 {
+            return Err(invalid("hand does not reach showdown"));
+        }
+
+        let seats = self.remaining();
+        let hands = seats
+            .iter()
+            .map(|i| {
+                self.seats[*i]
+                    .hand
+                    .ok_or_else(|| invalid("hole cards are unknown"))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let game = Game::TexasHoldem(
+            Board(self.board.clone()),
+            hands.iter().copied().map(Named::from).collect(),
+        );
+
+        let ranked_hands = Game::rank_hands(game);
+        let grouped_hands = Game::group_hands(ranked_hands);
+        let sorted_hands = Game::sort_hands(grouped_hands);
+
+        Ok(sorted_hands
+            .into_iter()
+            .rev()
+            .map(|group| {
+                group
+                    .into_iter()
+                    .map(|named| {
+                        let position = hands.iter().position(|hand| hand.0 == named.hand[..]);
+                        seats[position.unwrap()]
+                    })
+                    .sorted()
+                    .collect()
+            })
+            .collect())
+    }
+
+    /// Chips won by every seat once the hand is over.
+    pub fn payout(&self, odd_chip: OddChip) -> Result<Vec<u64>, io::Error> {
+        if !self.is_finished() {
+            return Err(invalid("hand is not over"));
+        }
+
+        let remaining = self.remaining();
+        let ranking = if remaining.len() == 1 {
+            vec![remaining]
+        } else {
+            self.showdown()?
+        };
+
+        let players = self
+            .seats
+            .iter()
+            .map(|seat| Player {
+                contributed: seat.contributed,
+                folded: seat.folded,
+                cards: seat.hand.map_or_else(Vec::new, |hand| hand.0.to_vec()),
+            })
+            .collect::<Vec<_>>();
+
+        Ok(crate::payout::payout(
+            &players,
+            &ranking,
+            self.button,
+            odd_chip,
+        ))
+    }
+
+    fn post(&mut self, i: usize, amount: u64) {
+        let seat = &mut self.seats[i];
+        let amount = amount.min(seat.stack);
+
+        seat.stack -= amount;
+        seat.committed += amount;
+        seat.contributed += amount;
+    }
+
+    fn raise_range(&self, i: usize) -> Option<(u64, u64)> {
+        let seat = &self.seats[i];
+        let to_call = self.current_bet - seat.committed;
+
+        if seat.stack <= to_call || self.raise_closed[i] {
+            return None;
+        }
+
+        let all_in = seat.committed + seat.stack;
+
+        match self.stakes.limit {
+            Limit::FixedLimit => {
+                if self.bets >= FIXED_LIMIT_CAP {
+                    return None;
+                }
+
+                let size = match self.street {
+                    Street::Preflop | Street::Flop => self.stakes.big_blind,
+                    Street::Turn | Street::River => 2 * self.stakes.big_blind,
+                };
+                let to = (self.current_bet + size).min(all_in);
+
+                Some((to, to))
+            }
+            Limit::PotLimit => {
+                let min = (self.current_bet + self.last_raise).min(all_in);
+                let max = (self.current_bet + self.pot() + to_call).min(all_in);
+
+                Some((min, max))
+            }
+            Limit::NoLimit => {
+                let min = (self.current_bet + self.last_raise).min(all_in);
+
+                Some((min, all_in))
+            }
+        }
+    }
+
+    fn next_to_act(&self, from: usize) -> Option<usize> {
+        let n = self.seats.len();
+
+        let active = (0..n)
+            .filter(|i| !self.seats[*i].folded && !self.seats[*i].is_all_in())
+            .count();
+
+        (1..=n).map(|k| (from + k) % n).find(|j| {
+            let seat = &self.seats[*j];
+
+            if seat.folded || seat.is_all_in() {
+                return false;
+            }
+
+            if seat.committed < self.current_bet {
+                return true;
+            }
+
+            !self.acted[*j] && active > 1
+        })
+    }
+
+    fn known_cards(&self) -> Vec<Card> {
+        self.board
+            .iter()
+            .chain(
+                self.seats
+                    .iter()
+                    .filter_map(|seat| seat.hand.as_ref())
+                    .flat_map(|hand| hand.0.iter()),
+            )
+            .copied()
+            .collect()
+    }
+}
+
+fn invalid(reason: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, reason)
+}
+
+#[cfg(test)]
+mod tests {
+    use ::claim::*;
+
+    use std::str::FromStr;
+
+    use crate::card::parse_cards;
+    use crate::engine::Action;
+    use crate::engine::Engine;
+    use crate::engine::LegalAction;
+    use crate::engine::Limit;
+    use crate::engine::Stakes;
+    use crate::engine::Street;
+    use crate::game::HandOf2;
+    use crate::payout::OddChip;
+
+    fn stakes(limit: Limit) -> Stakes {
+        Stakes {
+            small_blind: 1,
+            big_blind: 2,
+            ante: 0,
+            limit,
+        }
+    }
+
+    #[test]
+    fn test_blinds() {
+        let engine = Engine::new(stakes(Limit::NoLimit), &[100, 100, 100], 0);
+
+        assert_eq!(engine.seats()[1].committed, 1);
+        assert_eq!(engine.seats()[2].committed, 2);
+        assert_eq!(engine.pot(), 3);
+        assert_eq!(engine.to_act(), Some(0));
+
+        let engine = Engine::new(stakes(Limit::NoLimit), &[100, 100], 0);
+
+        assert_eq!(engine.seats()[0].committed, 1);
+        assert_eq!(engine.seats()[1].committed, 2);
+        assert_eq!(engine.to_act(), Some(0));
+    }
+
+    #[test]
+    fn test_antes() {
+        let stakes = Stakes {
+            ante: 1,
+            ..stakes(Limit::NoLimit)
+        };
+        let engine = Engine::new(stakes, &[100, 100, 100], 0);
+
+        assert_eq!(engine.pot(), 6);
+        assert_eq!(engine.seats()[0].stack, 99);
+        assert_eq!(engine.seats()[0].committed, 0);
+    }
+
+    #[test]
+    fn test_big_blind_option() {
+        let mut engine = Engine::new(stakes(Limit::NoLimit), &[100, 100, 100], 0);
+
+        assert_ok!(engine.act(Action::Call));
+        assert_ok!(engine.act(Action::Call));
+        assert_eq!(engine.to_act(), Some(2));
+        assert_eq!(
+            engine.legal_actions(),
+            vec![
+                LegalAction::Fold,
+                LegalAction::Check,
+                LegalAction::Raise { min: 4, max: 100 }
+            ]
+        );
+
+        assert_ok!(engine.act(Action::Check));
+        assert_eq!(engine.to_act(), None);
+        assert!(!engine.is_finished());
+    }
+
+    #[test]
+    fn test_full_hand() {
+        let mut engine = Engine::new(stakes(Limit::NoLimit), &[100, 100, 100], 0);
+
+        engine
+            .set_hole_cards(0, HandOf2::from_str("AhAd").unwrap())
+            .unwrap();
+        engine
+            .set_hole_cards(1, HandOf2::from_str("KhKd").unwrap())
+            .unwrap();
+        engine
+            .set_hole_cards(2, HandOf2::from_str("7c2d").unwrap())
+            .unwrap();
+
+        assert_ok!(engine.act(Action::Raise(6)));
+        assert_ok!(engine.act(Action::Call));
+        assert_ok!(engine.act(Action::Fold));
+
+        assert_err!(engine.act(Action::Check));
+        assert_ok!(engine.deal(&parse_cards("2c5s9h")));
+        assert_eq!(engine.street(), Street::Flop);
+        assert_eq!(engine.to_act(), Some(1));
+
+        assert_ok!(engine.act(Action::Check));
+        assert_ok!(engine.act(Action::Bet(10)));
+        assert_ok!(engine.act(Action::Call));
+
+        assert_ok!(engine.deal(&parse_cards("Jd")));
+        assert_ok!(engine.act(Action::Check));
+        assert_ok!(engine.act(Action::Check));
+
+        assert_ok!(engine.deal(&parse_cards("3s")));
+        assert_ok!(engine.act(Action::Check));
+        assert_ok!(engine.act(Action::Check));
+
+        assert!(engine.is_finished());
+        assert_eq!(engine.pot(), 34);
+        assert_eq!(engine.showdown().unwrap(), vec![vec![0], vec![1]]);
+    }
+
+    #[test]
+    fn test_fold_ends_hand() {
+        let mut engine = Engine::new(stakes(Limit::NoLimit), &[100, 100], 0);
+
+        assert_err!(engine.payout(OddChip::LeftOfButton));
+        assert_ok!(engine.act(Action::Fold));
+        assert!(engine.is_finished());
+        assert_eq!(engine.remaining(), vec![1]);
+        assert_eq!(engine.payout(OddChip::LeftOfButton).unwrap(), vec![0, 3]);
+        assert_err!(engine.showdown());
+        assert_err!(engine.deal(&parse_cards("2c5s9h")));
+    }
+
+    #[test]
+    fn test_illegal_actions() {
+        let mut engine = Engine::new(stakes(Limit::NoLimit), &[100, 100, 100], 0);
+
+        assert_err!(engine.act(Action::Check));
+        assert_err!(engine.act(Action::Bet(10)));
+        assert_err!(engine.act(Action::Raise(3)));
+        assert_err!(engine.act(Action::Raise(101)));
+        assert_err!(engine.deal(&parse_cards("2c5s9h")));
+
+        assert_ok!(engine.act(Action::Raise(10)));
+        assert_err!(engine.act(Action::Raise(15)));
+        assert_ok!(engine.act(Action::Raise(18)));
+    }
+
+    #[test]
+    fn test_duplicate_cards() {
+        let mut engine = Engine::new(stakes(Limit::NoLimit), &[100, 100], 0);
+
+        engine
+            .set_hole_cards(0, HandOf2::from_str("AhAd").unwrap())
+            .unwrap();
+        assert_err!(engine.set_hole_cards(1, HandOf2::from_str("AhKd").unwrap()));
+
+        assert_ok!(engine.act(Action::Call));
+        assert_ok!(engine.act(Action::Check));
+        assert_err!(engine.deal(&parse_cards("Ad5s9h")));
+        assert_err!(engine.deal(&parse_cards("5s9h")));
+    }
+
+    #[test]
+    fn test_pot_limit() {
+        let mut engine = Engine::new(stakes(Limit::PotLimit), &[100, 100, 100], 0);
+
+        assert_eq!(
+            engine.legal_actions()[2],
+            LegalAction::Raise { min: 4, max: 7 }
+        );
+        assert_ok!(engine.act(Action::Raise(7)));
+        assert_eq!(
+            engine.legal_actions()[2],
+            LegalAction::Raise { min: 12, max: 23 }
+        );
+    }
+
+    #[test]
+    fn test_fixed_limit_cap() {
+        let mut engine = Engine::new(stakes(Limit::FixedLimit), &[100, 100, 100], 0);
+
+        assert_eq!(
+            engine.legal_actions()[2],
+            LegalAction::Raise { min: 4, max: 4 }
+        );
+        assert_ok!(engine.act(Action::Raise(4)));
+        assert_ok!(engine.act(Action::Raise(6)));
+        assert_ok!(engine.act(Action::Raise(8)));
+        assert_eq!(engine.legal_actions().len(), 2);
+        assert_err!(engine.act(Action::Raise(10)));
+    }
+
+    #[test]
+    fn test_short_all_in_does_not_reopen() {
+        let mut engine = Engine::new(stakes(Limit::NoLimit), &[100, 100, 13], 0);
+
+        assert_ok!(engine.act(Action::Raise(10)));
+        assert_ok!(engine.act(Action::Call));
+        assert_ok!(engine.act(Action::Raise(13)));
+
+        assert_eq!(engine.to_act(), Some(0));
+        assert_eq!(
+            engine.legal_actions(),
+            vec![LegalAction::Fold, LegalAction::Call(3)]
+        );
+        assert_ok!(engine.act(Action::Call));
+        assert_ok!(engine.act(Action::Call));
+        assert_eq!(engine.to_act(), None);
+    }
+
+    #[test]
+    fn test_all_in_runs_out_board() {
+        let mut engine = Engine::new(stakes(Limit::NoLimit), &[50, 100], 0);
+
+        engine
+            .set_hole_cards(0, HandOf2::from_str("AhAd").unwrap())
+            .unwrap();
+        engine
+            .set_hole_cards(1, HandOf2::from_str("KhKd").unwrap())
+            .unwrap();
+
+        assert_ok!(engine.act(Action::Raise(50)));
+        assert_ok!(engine.act(Action::Call));
+        assert_eq!(engine.to_act(), None);
+
+        assert_ok!(engine.deal(&parse_cards("2c5s9h")));
+        assert_eq!(engine.to_act(), None);
+        assert_ok!(engine.deal(&parse_cards("Kc")));
+        assert_ok!(engine.deal(&parse_cards("3s")));
+
+        assert!(engine.is_finished());
+        assert_eq!(engine.showdown().unwrap(), vec![vec![1], vec![0]]);
+        assert_eq!(engine.payout(OddChip::LeftOfButton).unwrap(), vec![0, 100]);
+    }
+}
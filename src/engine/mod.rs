@@ -0,0 +1,81 @@
+mod impl_engine;
+
+use crate::card::Card;
+use crate::game::HandOf2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Street {
+    Preflop,
+    Flop,
+    Turn,
+    River,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Limit {
+    NoLimit,
+    PotLimit,
+    /// Small bet of one big blind preflop and on the flop, big bet of two
+    /// big blinds on the turn and river, capped at four bets per street.
+    FixedLimit,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Stakes {
+    pub small_blind: u64,
+    pub big_blind: u64,
+    pub ante: u64,
+    pub limit: Limit,
+}
+
+/// Bet and raise amounts are the total the player has put in on the
+/// current street once the action is complete ("raise to").
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Action {
+    Fold,
+    Check,
+    Call,
+    Bet(u64),
+    Raise(u64),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LegalAction {
+    Fold,
+    Check,
+    Call(u64),
+    Bet { min: u64, max: u64 },
+    Raise { min: u64, max: u64 },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Seat {
+    pub stack: u64,
+    /// Chips put in on the current street.
+    pub committed: u64,
+    /// Chips put in over the whole hand, antes included.
+    pub contributed: u64,
+    pub folded: bool,
+    pub hand: Option<HandOf2>,
+}
+
+impl Seat {
+    pub fn is_all_in(&self) -> bool {
+        !self.folded && self.stack == 0
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Engine {
+    stakes: Stakes,
+    seats: Vec<Seat>,
+    button: usize,
+    street: Street,
+    board: Vec<Card>,
+    to_act: Option<usize>,
+    current_bet: u64,
+    last_raise: u64,
+    bets: u64,
+    acted: Vec<bool>,
+    raise_closed: Vec<bool>,
+}
@@ -0,0 +1,103 @@
+use ::itertools::Itertools;
+
+use crate::card::permutation::Permutation;
+use crate::card::Card;
+use crate::game::Board;
+
+/// Suit-isomorphic representative of a board together with hole cards.
+///
+/// Both card groups are sorted and suits are relabelled so that the
+/// (hand, board) pair is the smallest among all 24 suit permutations.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Canonical {
+    pub hand: Vec<Card>,
+    pub board: Board,
+}
+
+impl Canonical {
+    /// Applies the permutation returned by `Board::canonical` to get the
+    /// original board and hand back.
+    pub fn restore(&self, permutation: Permutation) -> (Board, Vec<Card>) {
+        (
+            Board(permutation.cards(&self.board.0)),
+            permutation.cards(&self.hand),
+        )
+    }
+}
+
+impl Board {
+    pub fn canonical(&self, hand: &[Card]) -> (Canonical, Permutation) {
+        Permutation::all()
+            .into_iter()
+            .map(|permutation| {
+                let hand = permutation.cards(hand).into_iter().sorted().collect();
+                let board = permutation.cards(&self.0).into_iter().sorted().collect();
+
+                (
+                    Canonical {
+                        hand,
+                        board: Board(board),
+                    },
+                    permutation.inverse(),
+                )
+            })
+            .min_by(|(a, _), (b, _)| (&a.hand, &a.board.0).cmp(&(&b.hand, &b.board.0)))
+            .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use std::str::FromStr;
+
+    use ::itertools::Itertools;
+
+    use crate::game::Board;
+    use crate::game::HandOf2;
+
+    #[test]
+    fn test_flop_classes() {
+        let classes = crate::card::all_cards()
+            .into_iter()
+            .combinations(3)
+            .map(|cards| Board(cards).canonical(&[]).0)
+            .collect::<HashSet<_>>();
+
+        assert_eq!(classes.len(), 1755);
+    }
+
+    #[test]
+    fn test_isomorphic_inputs() {
+        let a = Board::from_str("Ah7h2c").unwrap();
+        let b = Board::from_str("2d7sAs").unwrap();
+
+        assert_eq!(a.canonical(&[]).0, b.canonical(&[]).0);
+
+        let hand_a = HandOf2::from_str("KhQd").unwrap();
+        let hand_b = HandOf2::from_str("KsQh").unwrap();
+
+        assert_eq!(a.canonical(&hand_a.0).0, b.canonical(&hand_b.0).0);
+
+        let hand_c = HandOf2::from_str("KsQd").unwrap();
+        assert_ne!(a.canonical(&hand_a.0).0, b.canonical(&hand_c.0).0);
+    }
+
+    #[test]
+    fn test_restore() {
+        let board = Board::from_str("Ah7h2cKs").unwrap();
+        let hand = crate::card::parse_cards("QdJh");
+
+        let (canonical, permutation) = board.canonical(&hand);
+        let (restored_board, restored_hand) = canonical.restore(permutation);
+
+        assert_eq!(
+            restored_board.0.into_iter().sorted().collect::<Vec<_>>(),
+            board.0.into_iter().sorted().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            restored_hand.into_iter().sorted().collect::<Vec<_>>(),
+            hand.into_iter().sorted().collect::<Vec<_>>()
+        );
+    }
+}
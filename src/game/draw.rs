@@ -0,0 +1,205 @@
+//! What each of the 32 ways to discard from a five-card draw hand leads to.
+
+use std::collections::HashMap;
+use std::convert::TryInto;
+use std::io;
+
+use ::itertools::Either;
+use ::itertools::Itertools;
+use ::rand::seq::SliceRandom;
+use ::rand::Rng;
+
+use crate::card::Card;
+use crate::combination::Combination;
+use crate::game::equity::n_choose_k;
+use crate::game::HandOf5;
+use crate::game::Variant;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Draw {
+    pub discard: Vec<Card>,
+    /// Probability of each final combination, keyed by `Combination::category`.
+    pub categories: HashMap<&'static str, f64>,
+    /// Share of the pot won against the opponents' final hands, ties split
+    /// evenly; 1 without opponents.
+    pub win: f64,
+}
+
+/// Every discard option, best first. Replacements come from the cards not
+/// in `hand`, `dead` or the opponents' hands; they are all enumerated when
+/// there are at most `limit` draws, otherwise `limit` random ones are taken.
+/// Discards left without a draw, when too few cards are unknown, are skipped.
+pub fn advise<R: Rng + ?Sized>(
+    hand: &HandOf5,
+    dead: &[Card],
+    opponents: &[HandOf5],
+    limit: usize,
+    rng: &mut R,
+) -> io::Result<Vec<Draw>> {
+    if limit == 0 {
+        return Err(super::invalid("no draws allowed".to_string()));
+    }
+
+    let known = hand
+        .0
+        .iter()
+        .chain(dead)
+        .chain(opponents.iter().flat_map(|hand| &hand.0))
+        .collect::<Vec<_>>();
+
+    for (i, card) in known.iter().enumerate() {
+        if known[..i].contains(card) {
+            return Err(super::invalid(format!("card {} is dealt twice", card)));
+        }
+    }
+
+    let mut unknown = crate::card::all_cards()
+        .into_iter()
+        .filter(|card| !known.contains(&card))
+        .collect::<Vec<_>>();
+    let opponents = opponents
+        .iter()
+        .map(|hand| Combination::from_variant(Variant(hand.0)))
+        .collect::<Vec<_>>();
+
+    let draws = (0..1 << 5)
+        .filter_map(|mask: usize| {
+            let (discard, held): (Vec<Card>, Vec<Card>) =
+                hand.0.iter().enumerate().partition_map(|(i, card)| {
+                    if mask & (1 << i) != 0 {
+                        Either::Left(*card)
+                    } else {
+                        Either::Right(*card)
+                    }
+                });
+
+            let draws = if n_choose_k(unknown.len(), discard.len()) <= limit {
+                unknown
+                    .iter()
+                    .copied()
+                    .combinations(discard.len())
+                    .collect::<Vec<_>>()
+            } else {
+                (0..limit)
+                    .map(|_| unknown.partial_shuffle(rng, discard.len()).0.to_vec())
+                    .collect::<Vec<_>>()
+            };
+
+            if draws.is_empty() {
+                return None;
+            }
+
+            let mut categories = HashMap::new();
+            let mut win = 0.0;
+
+            for draw in &draws {
+                let cards: [Card; 5] = held
+                    .iter()
+                    .chain(draw)
+                    .copied()
+                    .collect::<Vec<_>>()
+                    .try_into()
+                    .unwrap();
+                let combination = Combination::from_variant(Variant(cards));
+
+                *categories.entry(combination.category()).or_insert(0.0) += 1.0;
+
+                if opponents.iter().all(|opponent| combination >= *opponent) {
+                    let ties = opponents
+                        .iter()
+                        .filter(|opponent| **opponent == combination)
+                        .count();
+                    win += 1.0 / (ties + 1) as f64;
+                }
+            }
+
+            for probability in categories.values_mut() {
+                *probability /= draws.len() as f64;
+            }
+
+            Some(Draw {
+                discard,
+                categories,
+                win: win / draws.len() as f64,
+            })
+        })
+        .sorted_by(|a, b| b.win.partial_cmp(&a.win).unwrap())
+        .collect();
+
+    Ok(draws)
+}
+
+#[cfg(test)]
+mod tests {
+    use ::claim::*;
+
+    use std::str::FromStr;
+
+    use ::rand::rngs::StdRng;
+    use ::rand::SeedableRng;
+
+    use crate::card::parse_cards;
+    use crate::game::draw::advise;
+    use crate::game::HandOf5;
+
+    #[test]
+    fn test_advise_pat_hand() {
+        let hand = HandOf5::from_str("AhAdAcAsKd").unwrap();
+        let mut rng = StdRng::seed_from_u64(0);
+
+        let draws = advise(&hand, &[], &[], 100, &mut rng).unwrap();
+        assert_eq!(draws.len(), 32);
+
+        let pat = draws.iter().find(|draw| draw.discard.is_empty()).unwrap();
+        assert_eq!(pat.categories.get("four_of_a_kind"), Some(&1.0));
+        assert_eq!(pat.win, 1.0);
+
+        for draw in &draws {
+            let total = draw.categories.values().sum::<f64>();
+            assert!((total - 1.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_advise_against_opponent() {
+        let hand = HandOf5::from_str("2c3d4h5s9c").unwrap();
+        let opponent = HandOf5::from_str("AcAdKhQs7c").unwrap();
+        let mut rng = StdRng::seed_from_u64(0);
+
+        let draws = advise(&hand, &parse_cards("6d"), &[opponent], 1000, &mut rng).unwrap();
+
+        // Only a straight beats aces: Ah, As and three sixes among 41 cards.
+        let best = &draws[0];
+        assert_eq!(best.discard, parse_cards("9c"));
+        assert!((best.win - 5.0 / 41.0).abs() < 1e-9);
+        assert!((best.categories["straight"] - 5.0 / 41.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_advise_duplicate_cards() {
+        let hand = HandOf5::from_str("2c3d4h5s9c").unwrap();
+        let mut rng = StdRng::seed_from_u64(0);
+
+        let error = advise(&hand, &parse_cards("9c"), &[], 10, &mut rng).unwrap_err();
+        assert_eq!(error.to_string(), "card 9c is dealt twice");
+    }
+
+    #[test]
+    fn test_advise_without_draws() {
+        let hand = HandOf5::from_str("2c3d4h5s9c").unwrap();
+        let mut rng = StdRng::seed_from_u64(0);
+
+        assert_err!(advise(&hand, &[], &[], 0, &mut rng));
+
+        // Two unknown cards are too few to replace three or more.
+        let dead = crate::card::all_cards()
+            .into_iter()
+            .filter(|card| !hand.0.contains(card))
+            .skip(2)
+            .collect::<Vec<_>>();
+        let draws = advise(&hand, &dead, &[], 10, &mut rng).unwrap();
+
+        assert_eq!(draws.len(), 1 + 5 + 10);
+        assert!(draws.iter().all(|draw| draw.discard.len() <= 2));
+    }
+}
@@ -0,0 +1,296 @@
+use std::convert::TryInto;
+use std::io;
+
+use ::itertools::Itertools;
+use ::rand::seq::SliceRandom;
+use ::rand::Rng;
+
+use crate::card::notation;
+use crate::card::Card;
+use crate::combination::Combination;
+use crate::game::Board;
+use crate::game::Game;
+use crate::game::HandOf2;
+use crate::game::HandOf4;
+
+/// Hands all-in against each other, written `AhKh vs QsQd board 2h7h9c`;
+/// two hole cards for hold'em, four for Omaha.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Spot {
+    pub hands: Vec<Vec<Card>>,
+    pub board: Vec<Card>,
+}
+
+impl Spot {
+    pub fn parse(line: &str) -> io::Result<Self> {
+        let (hands, board) = match line.split_once(" board ") {
+            Some((hands, board)) => (hands, cards(board)?),
+            None => (line, vec![]),
+        };
+        let hands = hands
+            .split(" vs ")
+            .map(|hand| cards(hand.trim()))
+            .collect::<io::Result<Vec<_>>>()?;
+
+        if hands.len() < 2 {
+            return Err(invalid("equity needs at least 2 hands separated by vs"));
+        }
+        if board.len() > 5 {
+            return Err(invalid(format!("board has {} cards", board.len())));
+        }
+        if !matches!(hands[0].len(), 2 | 4) || hands.iter().any(|hand| hand.len() != hands[0].len())
+        {
+            return Err(invalid("hands must all have 2 or all have 4 cards"));
+        }
+
+        let all = board
+            .iter()
+            .chain(hands.iter().flatten())
+            .collect::<Vec<_>>();
+        if let Some((_, card)) = all
+            .iter()
+            .enumerate()
+            .find(|(i, card)| all[..*i].contains(card))
+        {
+            return Err(invalid(format!("card {} is dealt twice", card)));
+        }
+
+        Ok(Self { hands, board })
+    }
+
+    /// Hold'em or Omaha equity depending on the number of hole cards.
+    pub fn equity<R: Rng + ?Sized>(&self, limit: usize, rng: &mut R) -> Vec<f64> {
+        let hands = self.hands.iter().map(|hand| hand.as_slice());
+
+        match self.hands[0].len() {
+            2 => {
+                let hands = hands
+                    .map(|hand| HandOf2(hand.try_into().unwrap()))
+                    .collect::<Vec<_>>();
+                texas_holdem(&self.board, &hands, limit, rng)
+            }
+            _ => {
+                let hands = hands
+                    .map(|hand| HandOf4(hand.try_into().unwrap()))
+                    .collect::<Vec<_>>();
+                omaha_holdem(&self.board, &hands, limit, rng)
+            }
+        }
+    }
+}
+
+/// Share of the pot each hand wins on average over the runouts of `board`,
+/// ties split evenly. Every runout is enumerated when there are at most
+/// `limit` of them, otherwise `limit` random runouts are drawn.
+pub fn texas_holdem<R: Rng + ?Sized>(
+    board: &[Card],
+    hands: &[HandOf2],
+    limit: usize,
+    rng: &mut R,
+) -> Vec<f64> {
+    let known = hands.iter().flat_map(|hand| hand.0).collect::<Vec<_>>();
+
+    equity(board, &known, hands.len(), limit, rng, |board, i| {
+        Game::texas_holdem_combination(board, hands[i])
+    })
+}
+
+pub fn omaha_holdem<R: Rng + ?Sized>(
+    board: &[Card],
+    hands: &[HandOf4],
+    limit: usize,
+    rng: &mut R,
+) -> Vec<f64> {
+    let known = hands.iter().flat_map(|hand| hand.0).collect::<Vec<_>>();
+
+    equity(board, &known, hands.len(), limit, rng, |board, i| {
+        Game::omaha_holdem_combination(board, hands[i])
+    })
+}
+
+fn equity<R: Rng + ?Sized, F: Fn(&Board, usize) -> Combination>(
+    board: &[Card],
+    known: &[Card],
+    players: usize,
+    limit: usize,
+    rng: &mut R,
+    combination: F,
+) -> Vec<f64> {
+    assert!(board.len() <= 5, "board has more than 5 cards");
+    assert_eq!(
+        board.iter().chain(known).unique().count(),
+        board.len() + known.len(),
+        "cards are dealt twice"
+    );
+
+    let missing = 5 - board.len();
+    let mut unknown = crate::card::all_cards()
+        .into_iter()
+        .filter(|card| !board.contains(card) && !known.contains(card))
+        .collect::<Vec<_>>();
+
+    let runouts = if n_choose_k(unknown.len(), missing) <= limit {
+        unknown
+            .into_iter()
+            .combinations(missing)
+            .collect::<Vec<_>>()
+    } else {
+        (0..limit)
+            .map(|_| unknown.partial_shuffle(rng, missing).0.to_vec())
+            .collect::<Vec<_>>()
+    };
+
+    let mut shares = vec![0.0; players];
+
+    for runout in &runouts {
+        let board = Board(board.iter().chain(runout).copied().collect());
+        let combinations = (0..players)
+            .map(|i| combination(&board, i))
+            .collect::<Vec<_>>();
+        let best = combinations.iter().max().unwrap();
+        let winners = combinations.iter().filter(|c| *c == best).count();
+
+        for (share, combination) in shares.iter_mut().zip(&combinations) {
+            if combination == best {
+                *share += 1.0 / winners as f64;
+            }
+        }
+    }
+
+    shares
+        .into_iter()
+        .map(|share| share / runouts.len() as f64)
+        .collect()
+}
+
+fn cards(raw: &str) -> io::Result<Vec<Card>> {
+    notation::cards(raw).ok_or_else(|| invalid(format!("invalid cards: {}", raw)))
+}
+
+fn invalid(message: impl ToString) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.to_string())
+}
+
+pub(crate) fn n_choose_k(n: usize, k: usize) -> usize {
+    (0..k.min(n + 1)).fold(1, |acc, i| acc * (n - i) / (i + 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use ::rand::rngs::StdRng;
+    use ::rand::SeedableRng;
+
+    use crate::card::parse_cards;
+    use crate::game::equity::n_choose_k;
+    use crate::game::equity::omaha_holdem;
+    use crate::game::equity::texas_holdem;
+    use crate::game::equity::Spot;
+    use crate::game::HandOf2;
+    use crate::game::HandOf4;
+
+    #[test]
+    fn test_n_choose_k() {
+        assert_eq!(n_choose_k(44, 2), 946);
+        assert_eq!(n_choose_k(48, 5), 1_712_304);
+        assert_eq!(n_choose_k(44, 0), 1);
+        assert_eq!(n_choose_k(1, 2), 0);
+    }
+
+    #[test]
+    fn test_texas_holdem_enumerated() {
+        let hands = [
+            HandOf2::from_str("AhAd").unwrap(),
+            HandOf2::from_str("KcKs").unwrap(),
+        ];
+        let mut rng = StdRng::seed_from_u64(0);
+
+        // Only a king on the river saves KK: 2 of the 44 remaining cards.
+        let equity = texas_holdem(&parse_cards("2c7d9h3s"), &hands, 1000, &mut rng);
+        assert!((equity[0] - 42.0 / 44.0).abs() < 1e-9);
+        assert!((equity[1] - 2.0 / 44.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_texas_holdem_split() {
+        let hands = [
+            HandOf2::from_str("2c3d").unwrap(),
+            HandOf2::from_str("4c5d").unwrap(),
+        ];
+        let mut rng = StdRng::seed_from_u64(0);
+
+        let equity = texas_holdem(&parse_cards("AhKhQhJhTh"), &hands, 1000, &mut rng);
+        assert_eq!(equity, vec![0.5, 0.5]);
+    }
+
+    #[test]
+    fn test_texas_holdem_sampled() {
+        let hands = [
+            HandOf2::from_str("AhAd").unwrap(),
+            HandOf2::from_str("7c2s").unwrap(),
+        ];
+        let mut rng = StdRng::seed_from_u64(0);
+
+        let equity = texas_holdem(&[], &hands, 2000, &mut rng);
+        assert!((equity[0] + equity[1] - 1.0).abs() < 1e-9);
+        assert!(equity[0] > 0.8 && equity[0] < 0.95);
+    }
+
+    #[test]
+    fn test_omaha_holdem_enumerated() {
+        let hands = [
+            HandOf4::from_str("AhKhQsJs").unwrap(),
+            HandOf4::from_str("2c2d7s8s").unwrap(),
+        ];
+        let mut rng = StdRng::seed_from_u64(0);
+
+        let equity = omaha_holdem(&parse_cards("ThJh2hQd9c"), &hands, 1000, &mut rng);
+        assert_eq!(equity, vec![1.0, 0.0]);
+    }
+
+    #[test]
+    fn test_spot() {
+        let spot = Spot::parse("AhKh vs QsQd board 2h7h9c").unwrap();
+        assert_eq!(spot.hands, vec![parse_cards("AhKh"), parse_cards("QsQd")]);
+        assert_eq!(spot.board, parse_cards("2h7h9c"));
+
+        let mut rng = StdRng::seed_from_u64(0);
+        let equity = Spot::parse("AhAd vs KcKs board 2c7d9h3s")
+            .unwrap()
+            .equity(1000, &mut rng);
+        assert!((equity[0] - 42.0 / 44.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_spot_invalid() {
+        let error = |line: &str| Spot::parse(line).unwrap_err().to_string();
+
+        assert_eq!(
+            error("AhAd"),
+            "equity needs at least 2 hands separated by vs"
+        );
+        assert_eq!(error("AhAd vs AhKs"), "card Ah is dealt twice");
+        assert_eq!(
+            error("AhAd vs KcKsQcQs"),
+            "hands must all have 2 or all have 4 cards"
+        );
+        assert_eq!(
+            error("AhAd vs KcKs board 2c3c4c5c6c7c"),
+            "board has 6 cards"
+        );
+        assert_eq!(error("AhAd vs KcKx"), "invalid cards: KcKx");
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_equity_duplicate_cards() {
+        let hands = [
+            HandOf2::from_str("AhAd").unwrap(),
+            HandOf2::from_str("AhKs").unwrap(),
+        ];
+        let mut rng = StdRng::seed_from_u64(0);
+
+        texas_holdem(&[], &hands, 10, &mut rng);
+    }
+}
@@ -1,74 +1,126 @@
 use std::collections::HashMap;
 use std::convert::TryInto;
+use std::hash::Hash;
 
 use ::itertools::Itertools;
 
 use crate::card::Card;
 use crate::combination::Combination;
+use crate::game::three_card_poker;
+use crate::game::three_card_poker::Outcome;
+use crate::game::three_card_poker::Paytable;
 use crate::game::Board;
 use crate::game::Game;
 use crate::game::HandOf2;
+use crate::game::HandOf3;
 use crate::game::HandOf4;
+use crate::game::HandOf5;
+use crate::game::Named;
+use crate::game::Ranking;
 use crate::game::Variant;
+use crate::three_card::ThreeCard;
 
 impl Game {
-    pub fn rank_hands(game: Self) -> Vec<(Vec<Card>, Combination)> {
-        match game {
-            Self::TexasHoldem(board, hands) => hands
-                .iter()
-                .map(|hand| {
-                    (
-                        hand.0.to_vec(),
-                        Self::texas_holdem_combination(board, *hand),
-                    )
-                })
-                .collect::<Vec<_>>(),
-            Self::OmahaHoldem(board, hands) => hands
-                .iter()
-                .map(|hand| {
+    pub fn rank_hands(game: Self) -> Vec<(Named<Vec<Card>>, Ranking)> {
+        fn ranked<H>(
+            hands: Vec<Named<H>>,
+            cards: fn(&H) -> &[Card],
+            ranking: impl Fn(H) -> Ranking,
+        ) -> Vec<(Named<Vec<Card>>, Ranking)> {
+            hands
+                .into_iter()
+                .map(|Named { name, hand }| {
                     (
-                        hand.0.to_vec(),
-                        Self::omaha_holdem_combination(board, *hand),
+                        Named {
+                            name,
+                            hand: cards(&hand).to_vec(),
+                        },
+                        ranking(hand),
                     )
                 })
-                .collect::<Vec<_>>(),
-            Self::FiveCardDraw(hands) => hands
-                .iter()
-                .map(|hand| (hand, Variant(hand.0)))
-                .map(|(hand, variant)| (hand.0.to_vec(), Combination::from_variant(variant)))
-                .collect::<Vec<_>>(),
+                .collect()
+        }
+
+        match game {
+            Self::TexasHoldem(board, hands) => ranked(hands, HandOf2::cards, |hand| {
+                Self::texas_holdem_combination(&board, hand).into()
+            }),
+            Self::OmahaHoldem(board, hands) => ranked(hands, HandOf4::cards, |hand| {
+                Self::omaha_holdem_combination(&board, hand).into()
+            }),
+            Self::FiveCardDraw(hands) => ranked(hands, HandOf5::cards, |hand| {
+                Combination::from_variant(Variant(hand.0)).into()
+            }),
+            // Players are only ranked among themselves here, see
+            // `settle_three_card_poker` for the game against the dealer.
+            Self::ThreeCardPoker(_, hands) => ranked(hands, HandOf3::cards, |hand| {
+                ThreeCard::from_cards(hand.0).into()
+            }),
         }
     }
 
-    pub fn group_hands(
-        hands: Vec<(Vec<Card>, Combination)>,
-    ) -> HashMap<Combination, Vec<Vec<Card>>> {
+    pub fn group_hands<R: Hash + Eq>(
+        hands: Vec<(Named<Vec<Card>>, R)>,
+    ) -> HashMap<R, Vec<Named<Vec<Card>>>> {
         hands
             .into_iter()
-            .fold(HashMap::new(), |mut acc, (hand, comb)| {
-                let hands = acc.get_mut(&comb).map_or_else(
-                    || vec![hand.clone()],
-                    |hands| {
-                        hands.push(hand.clone());
-                        hands.to_vec()
-                    },
-                );
-
-                acc.insert(comb, hands);
+            .fold(HashMap::new(), |mut acc, (hand, ranking)| {
+                acc.entry(ranking).or_insert_with(Vec::new).push(hand);
 
                 acc
             })
     }
 
-    pub fn sort_hands(grouped_hands: HashMap<Combination, Vec<Vec<Card>>>) -> Vec<Vec<Vec<Card>>> {
+    pub fn sort_hands<R: Ord>(
+        grouped_hands: HashMap<R, Vec<Named<Vec<Card>>>>,
+    ) -> Vec<Vec<Named<Vec<Card>>>> {
         grouped_hands
             .into_iter()
-            .sorted_by(|(comb_a, _), (comb_b, _)| comb_a.cmp(comb_b))
-            .map(|(_comb, hands)| hands)
+            .sorted_by(|(a, _), (b, _)| a.cmp(b))
+            .map(|(_ranking, hands)| hands)
             .collect()
     }
 
-    fn texas_holdem_combination(board: Board, hand: HandOf2) -> Combination {
+    /// The cards every hand plays, in the order of `rank_hands`: the best
+    /// five, or the whole hand in three-card poker.
+    pub fn best_cards(game: &Self) -> Vec<Vec<Card>> {
+        match game {
+            Self::TexasHoldem(board, hands) => hands
+                .iter()
+                .map(|named| Self::texas_holdem_best(board, named.hand).1 .0.to_vec())
+                .collect(),
+            Self::OmahaHoldem(board, hands) => hands
+                .iter()
+                .map(|named| Self::omaha_holdem_best(board, named.hand).1 .0.to_vec())
+                .collect(),
+            Self::FiveCardDraw(hands) => hands.iter().map(|named| named.hand.0.to_vec()).collect(),
+            Self::ThreeCardPoker(_, hands) => {
+                hands.iter().map(|named| named.hand.0.to_vec()).collect()
+            }
+        }
+    }
+
+    /// Each hand against the dealer, in the order of the hands; empty for
+    /// games without a dealer.
+    pub fn settle_three_card_poker(&self, paytable: &Paytable) -> Vec<Outcome> {
+        match self {
+            Self::ThreeCardPoker(dealer, hands) => hands
+                .iter()
+                .map(|named| three_card_poker::settle(*dealer, named.hand, paytable))
+                .collect(),
+            _ => vec![],
+        }
+    }
+
+    pub(crate) fn texas_holdem_combination(board: &Board, hand: HandOf2) -> Combination {
+        Self::texas_holdem_best(board, hand).0
+    }
+
+    pub(crate) fn omaha_holdem_combination(board: &Board, hand: HandOf4) -> Combination {
+        Self::omaha_holdem_best(board, hand).0
+    }
+
+    fn texas_holdem_best(board: &Board, hand: HandOf2) -> (Combination, Variant) {
         board
             .0
             .iter()
@@ -76,51 +128,66 @@ impl Game {
             .copied()
             .combinations(5)
             .map(|comb| Variant(comb.try_into().unwrap()))
-            .map(Combination::from_variant)
-            .sorted()
-            .max()
+            .map(|variant| (Combination::from_variant(variant), variant))
+            .max_by(|(a, _), (b, _)| a.cmp(b))
             .unwrap()
     }
 
-    fn omaha_holdem_combination(board: Board, hand: HandOf4) -> Combination {
+    fn omaha_holdem_best(board: &Board, hand: HandOf4) -> (Combination, Variant) {
         let hand_combinations = hand.0.iter().combinations(2);
         let board_combinations = board.0.iter().combinations(3);
 
         hand_combinations
             .into_iter()
-            .cartesian_product(board_combinations.into_iter())
-            .map(|(h, b)| {
-                h.into_iter()
-                    .chain(b.into_iter())
-                    .copied()
-                    .collect::<Vec<_>>()
-            })
+            .cartesian_product(board_combinations)
+            .map(|(h, b)| h.into_iter().chain(b).copied().collect::<Vec<_>>())
             .map(|cards| Variant(cards.try_into().unwrap()))
-            .map(Combination::from_variant)
-            .sorted()
-            .max()
+            .map(|variant| (Combination::from_variant(variant), variant))
+            .max_by(|(a, _), (b, _)| a.cmp(b))
             .unwrap()
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::str::FromStr;
+
     use ::itertools::Itertools;
 
+    use crate::card::parse_cards;
     use crate::card::Card;
     use crate::card::Rank;
     use crate::card::Suit;
     use crate::combination::Combination;
+    use crate::game::three_card_poker;
+    use crate::game::three_card_poker::Paytable;
     use crate::game::Board;
     use crate::game::Game;
     use crate::game::HandOf2;
+    use crate::game::HandOf3;
     use crate::game::HandOf4;
     use crate::game::HandOf5;
+    use crate::game::Named;
+    use crate::game::Ranking;
+
+    fn ranked(hands: Vec<(Vec<Card>, Combination)>) -> Vec<(Vec<Card>, Ranking)> {
+        hands
+            .into_iter()
+            .map(|(cards, comb)| (cards, comb.into()))
+            .collect()
+    }
+
+    fn named(hands: Vec<(Vec<Card>, Combination)>) -> Vec<(Named<Vec<Card>>, Combination)> {
+        hands
+            .into_iter()
+            .map(|(cards, comb)| (Named::from(cards), comb))
+            .collect()
+    }
 
     #[test]
     fn test_texas_holdem_ordering() {
         let game = Game::TexasHoldem(
-            Board([
+            Board(vec![
                 Card(Rank::Queen, Suit::Spades),
                 Card(Rank::King, Suit::Diamonds),
                 Card(Rank::King, Suit::Spades),
@@ -128,28 +195,32 @@ mod tests {
                 Card(Rank::Jack, Suit::Diamonds),
             ]),
             vec![
-                HandOf2([Card(Rank::King, Suit::Hearts), Card(Rank::Two, Suit::Clubs)]),
-                HandOf2([
+                Named::from(HandOf2([
+                    Card(Rank::King, Suit::Hearts),
+                    Card(Rank::Two, Suit::Clubs),
+                ])),
+                Named::from(HandOf2([
                     Card(Rank::King, Suit::Clubs),
                     Card(Rank::Seven, Suit::Diamonds),
-                ]),
-                HandOf2([
+                ])),
+                Named::from(HandOf2([
                     Card(Rank::Ace, Suit::Diamonds),
                     Card(Rank::Ten, Suit::Hearts),
-                ]),
-                HandOf2([
+                ])),
+                Named::from(HandOf2([
                     Card(Rank::Six, Suit::Diamonds),
                     Card(Rank::Six, Suit::Hearts),
-                ]),
+                ])),
             ],
         );
 
         assert_eq!(
             Game::rank_hands(game)
                 .into_iter()
+                .map(|(named, comb)| (named.hand, comb))
                 .sorted_by(|(_, comb_a), (_, comb_b)| comb_a.cmp(comb_b))
                 .collect::<Vec<_>>(),
-            vec![
+            ranked(vec![
                 (
                     vec![
                         Card(Rank::Six, Suit::Diamonds),
@@ -185,14 +256,14 @@ mod tests {
                         three: Rank::King
                     }
                 )
-            ]
+            ])
         );
     }
 
     #[test]
     fn test_omaha_holdem_ordering() {
         let game = Game::OmahaHoldem(
-            Board([
+            Board(vec![
                 Card(Rank::Queen, Suit::Spades),
                 Card(Rank::King, Suit::Diamonds),
                 Card(Rank::King, Suit::Spades),
@@ -200,33 +271,34 @@ mod tests {
                 Card(Rank::Jack, Suit::Diamonds),
             ]),
             vec![
-                HandOf4([
+                Named::from(HandOf4([
                     Card(Rank::King, Suit::Hearts),
                     Card(Rank::Two, Suit::Clubs),
                     Card(Rank::Eight, Suit::Clubs),
                     Card(Rank::Two, Suit::Diamonds),
-                ]),
-                HandOf4([
+                ])),
+                Named::from(HandOf4([
                     Card(Rank::King, Suit::Clubs),
                     Card(Rank::Seven, Suit::Diamonds),
                     Card(Rank::Seven, Suit::Hearts),
                     Card(Rank::Seven, Suit::Spades),
-                ]),
-                HandOf4([
+                ])),
+                Named::from(HandOf4([
                     Card(Rank::Ace, Suit::Diamonds),
                     Card(Rank::Ten, Suit::Hearts),
                     Card(Rank::Ace, Suit::Clubs),
                     Card(Rank::Ten, Suit::Clubs),
-                ]),
+                ])),
             ],
         );
 
         assert_eq!(
             Game::rank_hands(game)
                 .into_iter()
+                .map(|(named, comb)| (named.hand, comb))
                 .sorted_by(|(_, comb_a), (_, comb_b)| comb_a.cmp(comb_b))
                 .collect::<Vec<_>>(),
-            vec![
+            ranked(vec![
                 (
                     vec![
                         Card(Rank::King, Suit::Hearts),
@@ -260,42 +332,43 @@ mod tests {
                         three: Rank::King
                     }
                 )
-            ]
+            ])
         );
     }
 
     #[test]
     fn test_five_card_draw_ordering() {
         let game = Game::FiveCardDraw(vec![
-            HandOf5([
+            Named::from(HandOf5([
                 Card(Rank::King, Suit::Hearts),
                 Card(Rank::Two, Suit::Clubs),
                 Card(Rank::Eight, Suit::Clubs),
                 Card(Rank::Two, Suit::Diamonds),
                 Card(Rank::Two, Suit::Hearts),
-            ]),
-            HandOf5([
+            ])),
+            Named::from(HandOf5([
                 Card(Rank::King, Suit::Clubs),
                 Card(Rank::Seven, Suit::Diamonds),
                 Card(Rank::Seven, Suit::Hearts),
                 Card(Rank::Seven, Suit::Spades),
                 Card(Rank::Seven, Suit::Clubs),
-            ]),
-            HandOf5([
+            ])),
+            Named::from(HandOf5([
                 Card(Rank::Ace, Suit::Diamonds),
                 Card(Rank::Ten, Suit::Hearts),
                 Card(Rank::Ace, Suit::Clubs),
                 Card(Rank::Ten, Suit::Clubs),
                 Card(Rank::Ten, Suit::Diamonds),
-            ]),
+            ])),
         ]);
 
         assert_eq!(
             Game::rank_hands(game)
                 .into_iter()
+                .map(|(named, comb)| (named.hand, comb))
                 .sorted_by(|(_, comb_a), (_, comb_b)| comb_a.cmp(comb_b))
                 .collect::<Vec<_>>(),
-            vec![
+            ranked(vec![
                 (
                     vec![
                         Card(Rank::King, Suit::Hearts),
@@ -335,8 +408,49 @@ mod tests {
                         extra: [Rank::King]
                     }
                 )
+            ])
+        );
+    }
+
+    #[test]
+    fn test_three_card_poker_ordering() {
+        let game = Game::from_str("three-card-poker AsAdAc KhQhJh 2h3d4c 9s9c8d").unwrap();
+
+        assert_eq!(
+            Game::sort_hands(Game::group_hands(Game::rank_hands(game)))
+                .into_iter()
+                .map(|group| group[0].to_string())
+                .collect::<Vec<_>>(),
+            vec!["9s9c8d", "2h3d4c", "KhQhJh"]
+        );
+    }
+
+    #[test]
+    fn test_settle_three_card_poker() {
+        let paytable = Paytable::default();
+        let game = Game::from_str("three-card-poker Qh7d2c bob:5c6d7s 9s4c2h").unwrap();
+
+        let outcomes = game.settle_three_card_poker(&paytable);
+        assert_eq!(
+            outcomes,
+            vec![
+                three_card_poker::settle(
+                    HandOf3::from_str("Qh7d2c").unwrap(),
+                    HandOf3::from_str("5c6d7s").unwrap(),
+                    &paytable
+                ),
+                three_card_poker::settle(
+                    HandOf3::from_str("Qh7d2c").unwrap(),
+                    HandOf3::from_str("9s4c2h").unwrap(),
+                    &paytable
+                ),
             ]
         );
+        assert_eq!(outcomes[0].total(), 9);
+        assert!(!outcomes[1].plays);
+
+        let game = Game::from_str("five-card-draw 2c3c4c5c7d").unwrap();
+        assert!(game.settle_three_card_poker(&paytable).is_empty());
     }
 
     #[test]
@@ -375,9 +489,10 @@ mod tests {
         ];
 
         assert_eq!(
-            Game::group_hands(hands)
+            Game::group_hands(named(hands))
                 .into_iter()
                 .sorted_by(|(comb_a, _), (comb_b, _)| comb_a.cmp(comb_b))
+                .map(|(comb, hands)| (comb, hands.into_iter().map(|named| named.hand).collect()))
                 .collect::<Vec<(Combination, Vec<Vec<Card>>)>>(),
             vec![
                 (
@@ -455,10 +570,13 @@ mod tests {
             ),
         ];
 
-        let grouped_hands = Game::group_hands(hands);
+        let grouped_hands = Game::group_hands(named(hands));
 
         assert_eq!(
-            Game::sort_hands(grouped_hands),
+            Game::sort_hands(grouped_hands)
+                .into_iter()
+                .map(|group| group.into_iter().map(|named| named.hand).collect())
+                .collect::<Vec<Vec<Vec<Card>>>>(),
             vec![
                 vec![vec![
                     Card(Rank::Ace, Suit::Hearts),
@@ -484,7 +602,7 @@ mod tests {
 
     #[test]
     fn test_texas_holdem_combination() {
-        let board = Board([
+        let board = Board(vec![
             Card(Rank::Ace, Suit::Diamonds),
             Card(Rank::Ten, Suit::Hearts),
             Card(Rank::Nine, Suit::Hearts),
@@ -498,14 +616,17 @@ mod tests {
         ]);
 
         assert_eq!(
-            Game::texas_holdem_combination(board, hand),
-            Combination::Flush { rank: Rank::Ten }
+            Game::texas_holdem_combination(&board, hand),
+            Combination::Flush {
+                rank: Rank::Ten,
+                extra: [Rank::Nine, Rank::Eight, Rank::Seven, Rank::Four]
+            }
         );
     }
 
     #[test]
     fn test_omaha_holdem_combination() {
-        let board = Board([
+        let board = Board(vec![
             Card(Rank::Ace, Suit::Diamonds),
             Card(Rank::Ten, Suit::Hearts),
             Card(Rank::Nine, Suit::Hearts),
@@ -521,8 +642,51 @@ mod tests {
         ]);
 
         assert_eq!(
-            Game::omaha_holdem_combination(board, hand),
+            Game::omaha_holdem_combination(&board, hand),
             Combination::Straight { rank: Rank::Eight }
         );
     }
+
+    #[test]
+    fn test_best_cards() {
+        let game = Game::from_str("texas-holdem AdThKh8h7h 4hJd 2c2d").unwrap();
+
+        assert_eq!(
+            Game::best_cards(&game)
+                .into_iter()
+                .map(|variant| variant.iter().sorted().copied().collect::<Vec<_>>())
+                .collect::<Vec<_>>(),
+            vec![
+                parse_cards("4h7h8hThKh")
+                    .into_iter()
+                    .sorted()
+                    .collect::<Vec<_>>(),
+                parse_cards("2c2dAdKhTh")
+                    .into_iter()
+                    .sorted()
+                    .collect::<Vec<_>>(),
+            ]
+        );
+
+        let game = Game::from_str("five-card-draw 7h4s4h8c9h").unwrap();
+        assert_eq!(Game::best_cards(&game)[0], parse_cards("7h4s4h8c9h"));
+    }
+
+    #[test]
+    fn test_named_hands() {
+        let game = Game::from_str("texas-holdem 4cKs4h8s7s alice:Ad4s Ac4d bob:9s5s").unwrap();
+        let sorted_hands = Game::sort_hands(Game::group_hands(Game::rank_hands(game)));
+
+        assert_eq!(
+            sorted_hands
+                .iter()
+                .map(|group| group
+                    .iter()
+                    .map(|named| named.to_string())
+                    .sorted()
+                    .collect())
+                .collect::<Vec<Vec<_>>>(),
+            vec![vec!["Ac4d", "alice:Ad4s"], vec!["bob:9s5s"]]
+        );
+    }
 }
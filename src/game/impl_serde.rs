@@ -0,0 +1,309 @@
+use std::convert::TryInto;
+use std::fmt;
+use std::marker::PhantomData;
+
+use ::serde::de::value::MapAccessDeserializer;
+use ::serde::de::value::SeqAccessDeserializer;
+use ::serde::de::Error;
+use ::serde::de::MapAccess;
+use ::serde::de::SeqAccess;
+use ::serde::de::Visitor;
+use ::serde::Deserialize;
+use ::serde::Deserializer;
+use ::serde::Serialize;
+use ::serde::Serializer;
+
+use crate::card::Card;
+use crate::game::Board;
+use crate::game::Game;
+use crate::game::HandOf2;
+use crate::game::HandOf3;
+use crate::game::HandOf4;
+use crate::game::HandOf5;
+use crate::game::Kind;
+use crate::game::Named;
+
+const KINDS: [Kind; 4] = [
+    Kind::TexasHoldem,
+    Kind::OmahaHoldem,
+    Kind::FiveCardDraw,
+    Kind::ThreeCardPoker,
+];
+
+impl Serialize for Kind {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Kind {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let raw = String::deserialize(deserializer)?;
+
+        KINDS
+            .iter()
+            .copied()
+            .find(|kind| kind.to_string() == raw)
+            .ok_or_else(|| D::Error::custom(format!("unknown game: {}", raw)))
+    }
+}
+
+impl Serialize for Board {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Board {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let cards = Vec::<Card>::deserialize(deserializer)?;
+
+        if !(3..=5).contains(&cards.len()) {
+            return Err(D::Error::custom(format!(
+                "unexpected board size: {}",
+                cards.len()
+            )));
+        }
+
+        Ok(Board(cards))
+    }
+}
+
+/// A bare hand, or `{"name": "alice", "cards": [...]}` for a named one.
+#[derive(Serialize)]
+#[serde(untagged)]
+enum RawNamed<H> {
+    Named { name: String, cards: H },
+    Anonymous(H),
+}
+
+#[derive(Deserialize)]
+struct RawNamedHand<H> {
+    name: String,
+    cards: H,
+}
+
+/// Picks the form of `RawNamed` from the first token, where an untagged
+/// enum would replace the errors inside the hand with its own.
+struct NamedVisitor<H>(PhantomData<H>);
+
+impl<'de, H: Deserialize<'de>> Visitor<'de> for NamedVisitor<H> {
+    type Value = Named<H>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a hand or a named hand")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Self::Value, A::Error> {
+        H::deserialize(SeqAccessDeserializer::new(seq)).map(Named::from)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+        let RawNamedHand { name, cards } =
+            RawNamedHand::deserialize(MapAccessDeserializer::new(map))?;
+
+        Ok(Named {
+            name: Some(name),
+            hand: cards,
+        })
+    }
+}
+
+impl<H: Serialize> Serialize for Named<H> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match &self.name {
+            Some(name) => RawNamed::Named {
+                name: name.clone(),
+                cards: &self.hand,
+            },
+            None => RawNamed::Anonymous(&self.hand),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, H: Deserialize<'de>> Deserialize<'de> for Named<H> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(NamedVisitor(PhantomData))
+    }
+}
+
+/// `{"game": "texas-holdem", "board": [...], "hands": [[...], ...]}`, the
+/// board being left out for five-card draw and replaced by the dealer's
+/// hand in three-card poker.
+#[derive(Serialize, Deserialize)]
+struct RawGame<H> {
+    game: Kind,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    board: Option<Board>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    dealer: Option<HandOf3>,
+    hands: Vec<H>,
+}
+
+impl Serialize for Game {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::TexasHoldem(board, hands) => RawGame {
+                game: Kind::TexasHoldem,
+                board: Some(board.clone()),
+                dealer: None,
+                hands: hands.clone(),
+            }
+            .serialize(serializer),
+            Self::OmahaHoldem(board, hands) => RawGame {
+                game: Kind::OmahaHoldem,
+                board: Some(board.clone()),
+                dealer: None,
+                hands: hands.clone(),
+            }
+            .serialize(serializer),
+            Self::FiveCardDraw(hands) => RawGame {
+                game: Kind::FiveCardDraw,
+                board: None,
+                dealer: None,
+                hands: hands.clone(),
+            }
+            .serialize(serializer),
+            Self::ThreeCardPoker(dealer, hands) => RawGame {
+                game: Kind::ThreeCardPoker,
+                board: None,
+                dealer: Some(*dealer),
+                hands: hands.clone(),
+            }
+            .serialize(serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Game {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let raw = RawGame::<Named<Vec<Card>>>::deserialize(deserializer)?;
+
+        fn hands<H, E: Error, const N: usize>(
+            raw: Vec<Named<Vec<Card>>>,
+            hand: fn([Card; N]) -> H,
+        ) -> Result<Vec<Named<H>>, E> {
+            raw.into_iter()
+                .map(|Named { name, hand: cards }| {
+                    let len = cards.len();
+                    let cards: [Card; N] = cards
+                        .try_into()
+                        .map_err(|_| E::custom(format!("expected {} cards, got {}", N, len)))?;
+
+                    Ok(Named {
+                        name,
+                        hand: hand(cards),
+                    })
+                })
+                .collect()
+        }
+
+        let board = |board: Option<Board>| board.ok_or_else(|| D::Error::missing_field("board"));
+
+        match raw.game {
+            Kind::TexasHoldem => Ok(Game::TexasHoldem(
+                board(raw.board)?,
+                hands(raw.hands, HandOf2)?,
+            )),
+            Kind::OmahaHoldem => Ok(Game::OmahaHoldem(
+                board(raw.board)?,
+                hands(raw.hands, HandOf4)?,
+            )),
+            Kind::FiveCardDraw => Ok(Game::FiveCardDraw(hands(raw.hands, HandOf5)?)),
+            Kind::ThreeCardPoker => Ok(Game::ThreeCardPoker(
+                raw.dealer
+                    .ok_or_else(|| D::Error::missing_field("dealer"))?,
+                hands(raw.hands, HandOf3)?,
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::combination::Combination;
+    use crate::game::Board;
+    use crate::game::Game;
+    use crate::game::HandOf2;
+    use crate::game::Ranking;
+
+    #[test]
+    fn test_board_and_hand() {
+        let board = Board::from_str("4cKs4h8s7s").unwrap();
+        let json = serde_json::to_string(&board).unwrap();
+
+        assert_eq!(json, r#"["4c","Ks","4h","8s","7s"]"#);
+        assert_eq!(serde_json::from_str::<Board>(&json).unwrap(), board);
+        assert!(serde_json::from_str::<Board>(r#"["4c","Ks"]"#).is_err());
+
+        let hand = HandOf2::from_str("Ad4s").unwrap();
+        assert_eq!(serde_json::to_string(&hand).unwrap(), r#"["Ad","4s"]"#);
+        assert!(serde_json::from_str::<HandOf2>(r#"["Ad"]"#).is_err());
+    }
+
+    #[test]
+    fn test_game() {
+        let game = Game::from_str("texas-holdem 4cKs4h8s7s Ad4s Ac4d").unwrap();
+        let json = serde_json::to_string(&game).unwrap();
+
+        assert_eq!(
+            json,
+            r#"{"game":"texas-holdem","board":["4c","Ks","4h","8s","7s"],"hands":[["Ad","4s"],["Ac","4d"]]}"#
+        );
+        assert_eq!(serde_json::from_str::<Game>(&json).unwrap(), game);
+
+        let game = Game::from_str("texas-holdem 4cKs4h8s7s alice:Ad4s Ac4d").unwrap();
+        let json = serde_json::to_string(&game).unwrap();
+
+        assert!(json.contains(r#"{"name":"alice","cards":["Ad","4s"]},["Ac","4d"]"#));
+        assert_eq!(serde_json::from_str::<Game>(&json).unwrap(), game);
+
+        let game = Game::from_str("five-card-draw 7h4s4h8c9h Tc5h6dAc5c").unwrap();
+        let json = serde_json::to_string(&game).unwrap();
+
+        assert!(!json.contains("board"));
+        assert_eq!(serde_json::from_str::<Game>(&json).unwrap(), game);
+
+        let game = Game::from_str("three-card-poker Qh7d2c 5c6d7s").unwrap();
+        let json = serde_json::to_string(&game).unwrap();
+
+        assert_eq!(
+            json,
+            r#"{"game":"three-card-poker","dealer":["Qh","7d","2c"],"hands":[["5c","6d","7s"]]}"#
+        );
+        assert_eq!(serde_json::from_str::<Game>(&json).unwrap(), game);
+        assert!(serde_json::from_str::<Game>(r#"{"game":"three-card-poker","hands":[]}"#).is_err());
+    }
+
+    #[test]
+    fn test_combination() {
+        let game = Game::from_str("texas-holdem 4cKs4h8s7s Ad4s").unwrap();
+        let combination = match Game::rank_hands(game).pop().unwrap() {
+            (_, Ranking::Combination(combination)) => combination,
+            (_, ranking) => panic!("unexpected ranking: {}", ranking),
+        };
+        let json = serde_json::to_string(&combination).unwrap();
+
+        assert_eq!(
+            json,
+            r#"{"category":"three_of_a_kind","rank":"4","extra":["A","K"]}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<Combination>(&json).unwrap(),
+            combination
+        );
+    }
+
+    #[test]
+    fn test_game_invalid() {
+        assert!(serde_json::from_str::<Game>(r#"{"game":"stud","hands":[]}"#).is_err());
+        assert!(serde_json::from_str::<Game>(r#"{"game":"texas-holdem","hands":[]}"#).is_err());
+        assert!(serde_json::from_str::<Game>(
+            r#"{"game":"omaha-holdem","board":["4c","Ks","4h"],"hands":[["Ad","4s"]]}"#
+        )
+        .is_err());
+    }
+}
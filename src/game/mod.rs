@@ -1,123 +1,382 @@
+pub mod canonical;
+pub mod draw;
+pub mod equity;
 mod impl_game;
+#[cfg(feature = "serde")]
+mod impl_serde;
+pub mod nuts;
+pub mod strength;
+pub mod texture;
+pub mod three_card_poker;
 
 use std::convert::TryInto;
+use std::fmt;
+use std::io;
 use std::str::FromStr;
 
+use crate::card::notation;
 use crate::card::Card;
+use crate::combination::Combination;
+use crate::diagnostic;
+use crate::three_card::ThreeCard;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Game {
-    TexasHoldem(Board, Vec<HandOf2>),
-    OmahaHoldem(Board, Vec<HandOf4>),
-    FiveCardDraw(Vec<HandOf5>),
+    TexasHoldem(Board, Vec<Named<HandOf2>>),
+    OmahaHoldem(Board, Vec<Named<HandOf4>>),
+    FiveCardDraw(Vec<Named<HandOf5>>),
+    /// The dealer's hand first, then the players'.
+    ThreeCardPoker(HandOf3, Vec<Named<HandOf3>>),
 }
 
+impl Game {
+    pub fn kind(&self) -> Kind {
+        match self {
+            Self::TexasHoldem(..) => Kind::TexasHoldem,
+            Self::OmahaHoldem(..) => Kind::OmahaHoldem,
+            Self::FiveCardDraw(..) => Kind::FiveCardDraw,
+            Self::ThreeCardPoker(..) => Kind::ThreeCardPoker,
+        }
+    }
+}
+
+/// Errors carry a `Diagnostic` pointing at the offending token.
 impl FromStr for Game {
     type Err = std::io::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut tokens = s.split(' ').collect::<Vec<_>>();
-
-        let raw_game = tokens.remove(0);
-
-        match raw_game {
-            "texas-holdem" => {
-                let raw_board = tokens.remove(0);
-                let board = Board::from_str(raw_board).unwrap();
-                let hands = tokens
-                    .into_iter()
-                    .map(|token| HandOf2::from_str(token).unwrap())
-                    .collect::<Vec<HandOf2>>();
-                Ok(Self::TexasHoldem(board, hands))
+        let tokens = diagnostic::tokens(s);
+        let end = s.chars().count() + 1;
+
+        let (raw_game, tokens) = tokens
+            .split_first()
+            .ok_or_else(|| diagnostic::error(1, "", "empty line"))?;
+        let kind = parse::<Kind>(*raw_game)?;
+
+        let (board, hands) = match (kind, tokens) {
+            (Kind::FiveCardDraw, hands) => (None, hands),
+            (Kind::ThreeCardPoker, []) => return Err(diagnostic::error(end, "", "missing dealer")),
+            (_, []) => return Err(diagnostic::error(end, "", "missing board")),
+            (_, [board, hands @ ..]) => (Some(*board), hands),
+        };
+
+        let game = match (kind, board) {
+            (Kind::TexasHoldem, Some(board)) => Self::TexasHoldem(parse(board)?, parse_all(hands)?),
+            (Kind::OmahaHoldem, Some(board)) => Self::OmahaHoldem(parse(board)?, parse_all(hands)?),
+            (Kind::ThreeCardPoker, Some(dealer)) => {
+                Self::ThreeCardPoker(parse(dealer)?, parse_all(hands)?)
             }
-            "omaha-holdem" => {
-                let raw_board = tokens.remove(0);
-                let board = Board::from_str(raw_board).unwrap();
-                let hands = tokens
-                    .into_iter()
-                    .map(|token| HandOf4::from_str(token).unwrap())
-                    .collect::<Vec<HandOf4>>();
-                Ok(Self::OmahaHoldem(board, hands))
+            _ => Self::FiveCardDraw(parse_all(hands)?),
+        };
+
+        if hands.is_empty() {
+            return Err(diagnostic::error(end, "", "no hands"));
+        }
+
+        Ok(game)
+    }
+}
+
+/// Parses the token found at `column`, pointing errors at it.
+fn parse<T: FromStr<Err = io::Error>>((column, token): (usize, &str)) -> io::Result<T> {
+    T::from_str(token).map_err(|error| diagnostic::error(column, token, error))
+}
+
+fn parse_all<T: FromStr<Err = io::Error>>(tokens: &[(usize, &str)]) -> io::Result<Vec<T>> {
+    tokens.iter().map(|&token| parse(token)).collect()
+}
+
+/// The line format read by `FromStr`.
+impl fmt::Display for Game {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (board, hands) = match self {
+            Self::TexasHoldem(board, hands) => (Some(board.cards()), named(hands, HandOf2::cards)),
+            Self::OmahaHoldem(board, hands) => (Some(board.cards()), named(hands, HandOf4::cards)),
+            Self::FiveCardDraw(hands) => (None, named(hands, HandOf5::cards)),
+            Self::ThreeCardPoker(dealer, hands) => {
+                (Some(dealer.cards()), named(hands, HandOf3::cards))
             }
-            "five-card-draw" => {
-                let hands = tokens
-                    .into_iter()
-                    .map(|token| HandOf5::from_str(token).unwrap())
-                    .collect::<Vec<HandOf5>>();
-                Ok(Self::FiveCardDraw(hands))
+        };
+
+        write!(f, "{}", self.kind())?;
+        if let Some(board) = board {
+            write!(f, " ")?;
+            for card in board {
+                write!(f, "{}", card)?;
             }
-            _ => panic!("unknown game value: {}", raw_game),
         }
+        for hand in hands {
+            write!(f, " {}", hand)?;
+        }
+
+        Ok(())
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct Board([Card; 5]);
+fn named<H>(hands: &[Named<H>], cards: fn(&H) -> &[Card]) -> Vec<Named<Vec<Card>>> {
+    hands
+        .iter()
+        .map(|named| Named {
+            name: named.name.clone(),
+            hand: cards(&named.hand).to_vec(),
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Kind {
+    TexasHoldem,
+    OmahaHoldem,
+    FiveCardDraw,
+    ThreeCardPoker,
+}
+
+impl FromStr for Kind {
+    type Err = std::io::Error;
+
+    fn from_str(kind: &str) -> Result<Self, Self::Err> {
+        match kind {
+            "texas-holdem" => Ok(Self::TexasHoldem),
+            "omaha-holdem" => Ok(Self::OmahaHoldem),
+            "five-card-draw" => Ok(Self::FiveCardDraw),
+            "three-card-poker" => Ok(Self::ThreeCardPoker),
+            _ => Err(invalid(format!("unknown game: {}", kind))),
+        }
+    }
+}
+
+impl fmt::Display for Kind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
+            Self::TexasHoldem => "texas-holdem",
+            Self::OmahaHoldem => "omaha-holdem",
+            Self::FiveCardDraw => "five-card-draw",
+            Self::ThreeCardPoker => "three-card-poker",
+        };
+
+        write!(f, "{}", s)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Board(pub(crate) Vec<Card>);
+
+impl Board {
+    pub fn cards(&self) -> &[Card] {
+        &self.0
+    }
+}
 
 impl FromStr for Board {
     type Err = std::io::Error;
 
     fn from_str(board: &str) -> Result<Self, Self::Err> {
-        let cards: [Card; 5] = super::card::parse_cards(board).try_into().unwrap();
+        let cards =
+            notation::cards(board).ok_or_else(|| invalid(format!("invalid board: {}", board)))?;
 
-        Ok(Board(cards))
+        match cards.len() {
+            3..=5 => Ok(Board(cards)),
+            n => Err(invalid(format!("unexpected board size: {}", n))),
+        }
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct HandOf2([Card; 2]);
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct HandOf2(pub(crate) [Card; 2]);
+
+impl HandOf2 {
+    pub fn cards(&self) -> &[Card] {
+        &self.0
+    }
+}
 
 impl FromStr for HandOf2 {
     type Err = std::io::Error;
 
     fn from_str(hand: &str) -> Result<Self, Self::Err> {
-        let cards: [Card; 2] = super::card::parse_cards(hand).try_into().unwrap();
+        Ok(HandOf2(cards(hand)?))
+    }
+}
 
-        Ok(HandOf2(cards))
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct HandOf3(pub(crate) [Card; 3]);
+
+impl HandOf3 {
+    pub fn cards(&self) -> &[Card] {
+        &self.0
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct HandOf4([Card; 4]);
+impl FromStr for HandOf3 {
+    type Err = std::io::Error;
+
+    fn from_str(hand: &str) -> Result<Self, Self::Err> {
+        Ok(HandOf3(cards(hand)?))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct HandOf4(pub(crate) [Card; 4]);
+
+impl HandOf4 {
+    pub fn cards(&self) -> &[Card] {
+        &self.0
+    }
+}
 
 impl FromStr for HandOf4 {
     type Err = std::io::Error;
 
     fn from_str(hand: &str) -> Result<Self, Self::Err> {
-        let cards: [Card; 4] = super::card::parse_cards(hand).try_into().unwrap();
-
-        Ok(HandOf4(cards))
+        Ok(HandOf4(cards(hand)?))
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct HandOf5([Card; 5]);
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct HandOf5(pub(crate) [Card; 5]);
+
+impl HandOf5 {
+    pub fn cards(&self) -> &[Card] {
+        &self.0
+    }
+}
 
 impl FromStr for HandOf5 {
     type Err = std::io::Error;
 
     fn from_str(hand: &str) -> Result<Self, Self::Err> {
-        let cards: [Card; 5] = super::card::parse_cards(hand).try_into().unwrap();
-
-        Ok(HandOf5(cards))
+        Ok(HandOf5(cards(hand)?))
     }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Variant(pub [Card; 5]);
 
+/// What a hand is worth at showdown: five-card combinations in most games,
+/// three-card hands in three-card poker. Hands of one game are always of
+/// the same kind.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(::serde::Serialize, ::serde::Deserialize),
+    serde(untagged)
+)]
+pub enum Ranking {
+    Combination(Combination),
+    ThreeCard(ThreeCard),
+}
+
+impl Ranking {
+    pub fn category(&self) -> &'static str {
+        match self {
+            Self::Combination(combination) => combination.category(),
+            Self::ThreeCard(three_card) => three_card.category(),
+        }
+    }
+}
+
+impl From<Combination> for Ranking {
+    fn from(combination: Combination) -> Self {
+        Self::Combination(combination)
+    }
+}
+
+impl From<ThreeCard> for Ranking {
+    fn from(three_card: ThreeCard) -> Self {
+        Self::ThreeCard(three_card)
+    }
+}
+
+impl fmt::Display for Ranking {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Combination(combination) => write!(f, "{}", combination),
+            Self::ThreeCard(three_card) => write!(f, "{}", three_card),
+        }
+    }
+}
+
+/// A hand with the name of the player holding it, written `alice:AhKd`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Named<H> {
+    pub name: Option<String>,
+    pub hand: H,
+}
+
+impl<H> From<H> for Named<H> {
+    fn from(hand: H) -> Self {
+        Self { name: None, hand }
+    }
+}
+
+impl<H: FromStr<Err = std::io::Error>> FromStr for Named<H> {
+    type Err = std::io::Error;
+
+    fn from_str(token: &str) -> Result<Self, Self::Err> {
+        match token.rsplit_once(':') {
+            Some(("", _)) => Err(invalid(format!("missing name: {}", token))),
+            Some((_, "")) => Err(invalid(format!("missing cards: {}", token))),
+            Some((name, hand)) => Ok(Self {
+                name: Some(name.to_string()),
+                hand: H::from_str(hand)?,
+            }),
+            None => Ok(Self::from(H::from_str(token)?)),
+        }
+    }
+}
+
+impl fmt::Display for Named<Vec<Card>> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(name) = &self.name {
+            write!(f, "{}:", name)?;
+        }
+        for card in &self.hand {
+            write!(f, "{}", card)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The cards of a hand of exactly `N` cards.
+fn cards<const N: usize>(hand: &str) -> io::Result<[Card; N]> {
+    notation::cards(hand)
+        .ok_or_else(|| invalid(format!("invalid hand: {}", hand)))?
+        .try_into()
+        .map_err(|cards: Vec<Card>| {
+            invalid(format!(
+                "expected {} cards in {}, got {}",
+                N,
+                hand,
+                cards.len()
+            ))
+        })
+}
+
+fn invalid(reason: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, reason)
+}
+
 #[cfg(test)]
 mod tests {
+    use std::io;
     use std::str::FromStr;
 
     use crate::card::Card;
     use crate::card::Rank;
     use crate::card::Suit;
+    use crate::diagnostic;
     use crate::game::Board;
     use crate::game::Game;
     use crate::game::HandOf2;
     use crate::game::HandOf4;
     use crate::game::HandOf5;
+    use crate::game::Kind;
+    use crate::game::Named;
 
     #[test]
     fn test_game_texas_holdem_from_str() {
@@ -125,7 +384,7 @@ mod tests {
         assert_eq!(
             Game::from_str(s).unwrap(),
             Game::TexasHoldem(
-                Board([
+                Board(vec![
                     Card(Rank::Ace, Suit::Diamonds),
                     Card(Rank::Ace, Suit::Clubs),
                     Card(Rank::Ace, Suit::Hearts),
@@ -133,14 +392,14 @@ mod tests {
                     Card(Rank::King, Suit::Diamonds)
                 ]),
                 vec![
-                    HandOf2([
+                    Named::from(HandOf2([
                         Card(Rank::Queen, Suit::Diamonds),
                         Card(Rank::Jack, Suit::Diamonds),
-                    ]),
-                    HandOf2([
+                    ])),
+                    Named::from(HandOf2([
                         Card(Rank::Ten, Suit::Diamonds),
                         Card(Rank::Nine, Suit::Hearts),
-                    ]),
+                    ])),
                 ]
             )
         );
@@ -152,7 +411,7 @@ mod tests {
         assert_eq!(
             Game::from_str(s).unwrap(),
             Game::OmahaHoldem(
-                Board([
+                Board(vec![
                     Card(Rank::Ace, Suit::Diamonds),
                     Card(Rank::Ace, Suit::Clubs),
                     Card(Rank::Ace, Suit::Hearts),
@@ -160,18 +419,18 @@ mod tests {
                     Card(Rank::King, Suit::Diamonds)
                 ]),
                 vec![
-                    HandOf4([
+                    Named::from(HandOf4([
                         Card(Rank::Queen, Suit::Diamonds),
                         Card(Rank::Jack, Suit::Diamonds),
                         Card(Rank::Ten, Suit::Diamonds),
                         Card(Rank::Nine, Suit::Hearts),
-                    ]),
-                    HandOf4([
+                    ])),
+                    Named::from(HandOf4([
                         Card(Rank::Three, Suit::Diamonds),
                         Card(Rank::Four, Suit::Diamonds),
                         Card(Rank::Five, Suit::Diamonds),
                         Card(Rank::Six, Suit::Diamonds),
-                    ]),
+                    ])),
                 ]
             )
         );
@@ -183,30 +442,88 @@ mod tests {
         assert_eq!(
             Game::from_str(s).unwrap(),
             Game::FiveCardDraw(vec![
-                HandOf5([
+                Named::from(HandOf5([
                     Card(Rank::Ace, Suit::Diamonds),
                     Card(Rank::Ace, Suit::Clubs),
                     Card(Rank::Ace, Suit::Hearts),
                     Card(Rank::Ace, Suit::Spades),
                     Card(Rank::King, Suit::Diamonds)
-                ]),
-                HandOf5([
+                ])),
+                Named::from(HandOf5([
                     Card(Rank::Queen, Suit::Diamonds),
                     Card(Rank::Jack, Suit::Diamonds),
                     Card(Rank::Ten, Suit::Diamonds),
                     Card(Rank::Nine, Suit::Hearts),
                     Card(Rank::Three, Suit::Diamonds),
-                ]),
+                ])),
             ])
         );
     }
 
+    #[test]
+    fn test_kind() {
+        for kind in &[
+            Kind::TexasHoldem,
+            Kind::OmahaHoldem,
+            Kind::FiveCardDraw,
+            Kind::ThreeCardPoker,
+        ] {
+            assert_eq!(Kind::from_str(&kind.to_string()).unwrap(), *kind);
+        }
+
+        let game = Game::from_str("five-card-draw AdAcAhAsKd").unwrap();
+        assert_eq!(game.kind(), Kind::FiveCardDraw);
+
+        let error = Kind::from_str("stud").unwrap_err();
+        assert_eq!(error.to_string(), "unknown game: stud");
+    }
+
+    #[test]
+    fn test_game_from_str_errors() {
+        let error = |s| Game::from_str(s).unwrap_err().to_string();
+
+        assert_eq!(error(""), "empty line");
+        assert_eq!(error("stud AhKd"), "unknown game: stud");
+        assert_eq!(error("texas-holdem"), "missing board");
+        assert_eq!(error("texas-holdem 4cKs"), "unexpected board size: 2");
+        assert_eq!(error("texas-holdem 4cKs4x AhKd"), "invalid board: 4cKs4x");
+        assert_eq!(error("texas-holdem 4cKs4h8s7s"), "no hands");
+        assert_eq!(error("texas-holdem 4cKs4h AxKd"), "invalid hand: AxKd");
+        assert_eq!(
+            error("omaha-holdem 4cKs4h AhKd"),
+            "expected 4 cards in AhKd, got 2"
+        );
+        assert_eq!(error("five-card-draw"), "no hands");
+        assert_eq!(error("three-card-poker"), "missing dealer");
+        assert_eq!(
+            error("three-card-poker QhJd AhKd2c"),
+            "expected 3 cards in QhJd, got 2"
+        );
+    }
+
+    #[test]
+    fn test_game_from_str_diagnostic() {
+        let diagnostic = |s| {
+            let error = Game::from_str(s).unwrap_err();
+            let diagnostic = diagnostic::of(&error).unwrap();
+
+            (diagnostic.column, diagnostic.token.clone())
+        };
+
+        assert_eq!(diagnostic("  stud AhKd"), (3, "stud".to_string()));
+        assert_eq!(
+            diagnostic("texas-holdem 4cKs4h AhKd bob:AxKd"),
+            (26, "bob:AxKd".to_string())
+        );
+        assert_eq!(diagnostic("texas-holdem"), (13, "".to_string()));
+    }
+
     #[test]
     fn test_board_from_str() {
         let s = "AdAcAhAsKd";
         assert_eq!(
             Board::from_str(s).unwrap(),
-            Board([
+            Board(vec![
                 Card(Rank::Ace, Suit::Diamonds),
                 Card(Rank::Ace, Suit::Clubs),
                 Card(Rank::Ace, Suit::Hearts),
@@ -214,6 +531,9 @@ mod tests {
                 Card(Rank::King, Suit::Diamonds)
             ]),
         );
+
+        let error = Board::from_str("AdAc").unwrap_err();
+        assert_eq!(error.to_string(), "unexpected board size: 2");
     }
 
     #[test]
@@ -226,6 +546,10 @@ mod tests {
                 Card(Rank::Ace, Suit::Clubs),
             ]),
         );
+
+        let error = |s| HandOf2::from_str(s).unwrap_err().to_string();
+        assert_eq!(error("AxKd"), "invalid hand: AxKd");
+        assert_eq!(error("AhKdQc"), "expected 2 cards in AhKdQc, got 3");
     }
 
     #[test]
@@ -256,4 +580,38 @@ mod tests {
             ]),
         );
     }
+
+    #[test]
+    fn test_game_display() {
+        for line in [
+            "texas-holdem 4cKs4h8s7s alice:Ad4s Ac4d",
+            "omaha-holdem 4cKs4h AhKdQcJs bob:2c3c4d5d",
+            "five-card-draw 2c3c4c5c7d AsKsQsJsTs",
+            "three-card-poker Qh7d2c bob:5c6d7s AhKhJh",
+        ] {
+            assert_eq!(Game::from_str(line).unwrap().to_string(), line);
+        }
+    }
+
+    #[test]
+    fn test_named_from_str() {
+        let named = Named::<HandOf2>::from_str("alice:AdAc").unwrap();
+        assert_eq!(named.name, Some("alice".to_string()));
+        assert_eq!(named.hand, HandOf2::from_str("AdAc").unwrap());
+
+        let named = Named::<HandOf2>::from_str("AdAc").unwrap();
+        assert_eq!(named.name, None);
+        assert_eq!(named, Named::from(HandOf2::from_str("AdAc").unwrap()));
+
+        let error = Named::<HandOf2>::from_str("alice:").unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(error.to_string(), "missing cards: alice:");
+
+        let error = Named::<HandOf2>::from_str(":AhKh").unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(error.to_string(), "missing name: :AhKh");
+
+        assert!(Game::from_str("texas-holdem 4cKs4h8s7s alice:").is_err());
+        assert!(Game::from_str("texas-holdem 4cKs4h8s7s :AhKh").is_err());
+    }
 }
@@ -0,0 +1,215 @@
+use std::convert::TryInto;
+
+use ::itertools::Itertools;
+
+use crate::card::Card;
+use crate::combination::Combination;
+use crate::game::Board;
+use crate::game::Game;
+use crate::game::HandOf2;
+use crate::game::HandOf4;
+use crate::game::Variant;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rules {
+    TexasHoldem,
+    OmahaHoldem,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Holding {
+    pub cards: [Card; 2],
+    pub combination: Combination,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Standing {
+    pub combination: Combination,
+    /// Number of holdings not blocked by the hand that beat it.
+    pub beaten_by: usize,
+}
+
+impl Standing {
+    pub fn is_nuts(&self) -> bool {
+        self.beaten_by == 0
+    }
+}
+
+/// Every two-card holding possible on a board, strongest first.
+#[derive(Debug, Clone)]
+pub struct Nuts {
+    rules: Rules,
+    board: Board,
+    holdings: Vec<Holding>,
+}
+
+impl Nuts {
+    pub fn new(board: &Board, rules: Rules) -> Self {
+        let holdings = crate::card::all_cards()
+            .into_iter()
+            .filter(|card| !board.0.contains(card))
+            .combinations(2)
+            .map(|cards| {
+                let cards: [Card; 2] = cards.try_into().unwrap();
+                let combination = match rules {
+                    Rules::TexasHoldem => Game::texas_holdem_combination(board, HandOf2(cards)),
+                    Rules::OmahaHoldem => omaha_holding_combination(board, cards),
+                };
+
+                Holding { cards, combination }
+            })
+            .sorted_by(|a, b| b.combination.cmp(&a.combination))
+            .collect();
+
+        Self {
+            rules,
+            board: board.clone(),
+            holdings,
+        }
+    }
+
+    pub fn nuts(&self) -> Holding {
+        self.holdings[0]
+    }
+
+    pub fn top(&self, n: usize) -> &[Holding] {
+        &self.holdings[..n.min(self.holdings.len())]
+    }
+
+    pub fn holdings(&self) -> &[Holding] {
+        &self.holdings
+    }
+
+    pub fn standing_of_2(&self, hand: HandOf2) -> Standing {
+        let combination = match self.rules {
+            Rules::TexasHoldem => Game::texas_holdem_combination(&self.board, hand),
+            Rules::OmahaHoldem => omaha_holding_combination(&self.board, hand.0),
+        };
+
+        self.standing(combination, &hand.0)
+    }
+
+    pub fn standing_of_4(&self, hand: HandOf4) -> Standing {
+        let combination = Game::omaha_holdem_combination(&self.board, hand);
+
+        self.standing(combination, &hand.0)
+    }
+
+    fn standing(&self, combination: Combination, blockers: &[Card]) -> Standing {
+        let beaten_by = self
+            .holdings
+            .iter()
+            .take_while(|holding| holding.combination > combination)
+            .filter(|holding| !holding.cards.iter().any(|card| blockers.contains(card)))
+            .count();
+
+        Standing {
+            combination,
+            beaten_by,
+        }
+    }
+}
+
+fn omaha_holding_combination(board: &Board, cards: [Card; 2]) -> Combination {
+    board
+        .0
+        .iter()
+        .copied()
+        .combinations(3)
+        .map(|b| Variant([cards[0], cards[1], b[0], b[1], b[2]]))
+        .map(Combination::from_variant)
+        .max()
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::card::Card;
+    use crate::card::Rank;
+    use crate::card::Suit;
+    use crate::combination::Combination;
+    use crate::game::nuts::Nuts;
+    use crate::game::nuts::Rules;
+    use crate::game::Board;
+    use crate::game::HandOf2;
+    use crate::game::HandOf4;
+
+    #[test]
+    fn test_texas_holdem_nuts() {
+        let board = Board::from_str("AhKhQh2c3d").unwrap();
+        let nuts = Nuts::new(&board, Rules::TexasHoldem);
+
+        assert_eq!(
+            nuts.nuts().cards,
+            [
+                Card(Rank::Ten, Suit::Hearts),
+                Card(Rank::Jack, Suit::Hearts)
+            ]
+        );
+        assert_eq!(
+            nuts.nuts().combination,
+            Combination::StraightFlush { rank: Rank::Ten }
+        );
+        assert_eq!(nuts.holdings().len(), 1081);
+        assert_eq!(nuts.top(3).len(), 3);
+        assert!(nuts
+            .top(10)
+            .windows(2)
+            .all(|w| w[0].combination >= w[1].combination));
+    }
+
+    #[test]
+    fn test_omaha_holdem_nuts() {
+        let board = Board::from_str("AhKhQhJhTh").unwrap();
+
+        let nuts = Nuts::new(&board, Rules::TexasHoldem);
+        assert_eq!(
+            nuts.nuts().combination,
+            Combination::StraightFlush { rank: Rank::Ten }
+        );
+
+        let nuts = Nuts::new(&board, Rules::OmahaHoldem);
+        assert_eq!(
+            nuts.nuts().cards,
+            [
+                Card(Rank::Eight, Suit::Hearts),
+                Card(Rank::Nine, Suit::Hearts)
+            ]
+        );
+        assert_eq!(
+            nuts.nuts().combination,
+            Combination::StraightFlush { rank: Rank::Eight }
+        );
+    }
+
+    #[test]
+    fn test_standing_of_2() {
+        let board = Board::from_str("AhKhQh2c3d").unwrap();
+        let nuts = Nuts::new(&board, Rules::TexasHoldem);
+
+        let standing = nuts.standing_of_2(HandOf2::from_str("JhTh").unwrap());
+        assert!(standing.is_nuts());
+
+        let standing = nuts.standing_of_2(HandOf2::from_str("AsAd").unwrap());
+        assert!(!standing.is_nuts());
+        assert!(standing.beaten_by > 0);
+    }
+
+    #[test]
+    fn test_standing_of_4() {
+        let board = Board::from_str("AhKhQh2c3d").unwrap();
+        let nuts = Nuts::new(&board, Rules::OmahaHoldem);
+
+        let standing = nuts.standing_of_4(HandOf4::from_str("JhTh2d2s").unwrap());
+        assert!(standing.is_nuts());
+
+        let standing = nuts.standing_of_4(HandOf4::from_str("JsTs2d2s").unwrap());
+        assert_eq!(
+            standing.combination,
+            Combination::Straight { rank: Rank::Ten }
+        );
+        assert!(!standing.is_nuts());
+    }
+}
@@ -0,0 +1,171 @@
+use std::cmp::Ordering;
+use std::convert::TryInto;
+
+use ::itertools::Itertools;
+use ::rand::seq::SliceRandom;
+use ::rand::Rng;
+
+use crate::card::Card;
+use crate::combination::Combination;
+use crate::game::Board;
+use crate::game::Game;
+use crate::game::HandOf2;
+use crate::game::HandOf4;
+
+/// Showdown results of a hand against every opponent holding considered.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Strength {
+    pub wins: usize,
+    pub ties: usize,
+    pub losses: usize,
+}
+
+impl Strength {
+    pub fn texas_holdem(board: &Board, hand: HandOf2) -> Self {
+        assert_eq!(board.0.len(), 5, "board is not complete");
+
+        let combination = Game::texas_holdem_combination(board, hand);
+
+        unknown_cards(board, &hand.0)
+            .into_iter()
+            .combinations(2)
+            .map(|cards| HandOf2(cards.try_into().unwrap()))
+            .map(|opponent| Game::texas_holdem_combination(board, opponent))
+            .fold(Self::default(), |acc, other| acc.record(combination, other))
+    }
+
+    /// Enumerates every opponent hand when there are at most `limit` of them,
+    /// otherwise draws `limit` random opponent hands.
+    pub fn omaha_holdem<R: Rng + ?Sized>(
+        board: &Board,
+        hand: HandOf4,
+        limit: usize,
+        rng: &mut R,
+    ) -> Self {
+        assert_eq!(board.0.len(), 5, "board is not complete");
+
+        let combination = Game::omaha_holdem_combination(board, hand);
+        let mut cards = unknown_cards(board, &hand.0);
+
+        let opponents = if n_choose_4(cards.len()) <= limit {
+            cards
+                .into_iter()
+                .combinations(4)
+                .map(|cards| HandOf4(cards.try_into().unwrap()))
+                .collect::<Vec<_>>()
+        } else {
+            (0..limit)
+                .map(|_| {
+                    let (picked, _) = cards.partial_shuffle(rng, 4);
+                    HandOf4(picked.to_vec().try_into().unwrap())
+                })
+                .collect::<Vec<_>>()
+        };
+
+        opponents
+            .into_iter()
+            .map(|opponent| Game::omaha_holdem_combination(board, opponent))
+            .fold(Self::default(), |acc, other| acc.record(combination, other))
+    }
+
+    pub fn total(&self) -> usize {
+        self.wins + self.ties + self.losses
+    }
+
+    pub fn win_rate(&self) -> f64 {
+        self.wins as f64 / self.total() as f64
+    }
+
+    pub fn tie_rate(&self) -> f64 {
+        self.ties as f64 / self.total() as f64
+    }
+
+    pub fn loss_rate(&self) -> f64 {
+        self.losses as f64 / self.total() as f64
+    }
+
+    fn record(mut self, ours: Combination, theirs: Combination) -> Self {
+        match ours.cmp(&theirs) {
+            Ordering::Greater => self.wins += 1,
+            Ordering::Equal => self.ties += 1,
+            Ordering::Less => self.losses += 1,
+        }
+
+        self
+    }
+}
+
+fn unknown_cards(board: &Board, hand: &[Card]) -> Vec<Card> {
+    crate::card::all_cards()
+        .into_iter()
+        .filter(|card| !board.0.contains(card) && !hand.contains(card))
+        .collect()
+}
+
+fn n_choose_4(n: usize) -> usize {
+    if n < 4 {
+        0
+    } else {
+        n * (n - 1) * (n - 2) * (n - 3) / 24
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use ::rand::rngs::StdRng;
+    use ::rand::SeedableRng;
+
+    use crate::game::strength::Strength;
+    use crate::game::Board;
+    use crate::game::HandOf2;
+    use crate::game::HandOf4;
+
+    #[test]
+    fn test_texas_holdem_strength() {
+        let board = Board::from_str("AhKhQh2c3d").unwrap();
+
+        let strength = Strength::texas_holdem(&board, HandOf2::from_str("JhTh").unwrap());
+        assert_eq!(strength.total(), 990);
+        assert_eq!(strength.wins, 990);
+        assert_eq!(strength.win_rate(), 1.0);
+
+        let strength = Strength::texas_holdem(&board, HandOf2::from_str("7s4d").unwrap());
+        assert_eq!(strength.total(), 990);
+        assert!(strength.losses > strength.wins);
+    }
+
+    #[test]
+    fn test_texas_holdem_strength_board_plays() {
+        let board = Board::from_str("AhKhQhJhTh").unwrap();
+
+        let strength = Strength::texas_holdem(&board, HandOf2::from_str("2c3d").unwrap());
+        assert_eq!(strength.ties, 990);
+        assert_eq!(strength.tie_rate(), 1.0);
+    }
+
+    #[test]
+    fn test_omaha_holdem_strength_sampled() {
+        let board = Board::from_str("AhKhQh2c3d").unwrap();
+        let hand = HandOf4::from_str("JhTh2d2s").unwrap();
+        let mut rng = StdRng::seed_from_u64(0);
+
+        let strength = Strength::omaha_holdem(&board, hand, 200, &mut rng);
+        assert_eq!(strength.total(), 200);
+        assert_eq!(strength.wins, 200);
+
+        let hand = HandOf4::from_str("7s4d8c9c").unwrap();
+        let strength = Strength::omaha_holdem(&board, hand, 200, &mut rng);
+        assert_eq!(strength.total(), 200);
+        assert!(strength.losses > strength.wins);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_strength_incomplete_board() {
+        let board = Board::from_str("AhKhQh").unwrap();
+
+        Strength::texas_holdem(&board, HandOf2::from_str("JhTh").unwrap());
+    }
+}
@@ -0,0 +1,218 @@
+use std::collections::HashMap;
+
+use ::itertools::Itertools;
+
+use crate::card::Rank;
+use crate::card::Suit;
+use crate::game::Board;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Pairing {
+    Unpaired,
+    Paired,
+    TwoPaired,
+    Trips,
+    FullHouse,
+    Quads,
+}
+
+/// Suit distribution by the largest number of cards sharing a suit,
+/// measured against the size of the board.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Suitedness {
+    /// No two cards share a suit.
+    Rainbow,
+    /// At most two cards share a suit.
+    TwoTone,
+    /// Three or more cards share a suit, but not all of them.
+    FlushPossible,
+    /// All cards share a suit.
+    Monotone,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HighCardClass {
+    Low,
+    Middle,
+    Broadway,
+    Ace,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Texture {
+    pub pairing: Pairing,
+    pub suitedness: Suitedness,
+    pub high_card: HighCardClass,
+    /// Suits with at least three cards on the board.
+    pub flushes: Vec<Suit>,
+    /// Straights completable with two hole cards, identified the same way
+    /// as `Combination::Straight` (lowest rank, `Ace` for the wheel).
+    pub straights: Vec<Rank>,
+}
+
+impl Texture {
+    pub fn connectedness(&self) -> usize {
+        self.straights.len()
+    }
+}
+
+impl Board {
+    pub fn texture(&self) -> Texture {
+        Texture {
+            pairing: pairing(self),
+            suitedness: suitedness(self),
+            high_card: high_card(self),
+            flushes: flushes(self),
+            straights: straights(self),
+        }
+    }
+}
+
+fn pairing(board: &Board) -> Pairing {
+    let counts = board
+        .0
+        .iter()
+        .map(|card| card.0)
+        .fold(HashMap::new(), |mut acc, rank| {
+            *acc.entry(rank).or_insert(0) += 1;
+            acc
+        })
+        .into_values()
+        .sorted()
+        .rev()
+        .collect::<Vec<_>>();
+
+    match counts.as_slice() {
+        [4, ..] => Pairing::Quads,
+        [3, 2, ..] => Pairing::FullHouse,
+        [3, ..] => Pairing::Trips,
+        [2, 2, ..] => Pairing::TwoPaired,
+        [2, ..] => Pairing::Paired,
+        _ => Pairing::Unpaired,
+    }
+}
+
+fn suitedness(board: &Board) -> Suitedness {
+    let max = Suit::ALL
+        .iter()
+        .map(|suit| board.0.iter().filter(|card| card.1 == *suit).count())
+        .max()
+        .unwrap();
+
+    match max {
+        1 => Suitedness::Rainbow,
+        max if max == board.0.len() => Suitedness::Monotone,
+        2 => Suitedness::TwoTone,
+        _ => Suitedness::FlushPossible,
+    }
+}
+
+fn high_card(board: &Board) -> HighCardClass {
+    let rank = board.0.iter().map(|card| card.0).max().unwrap();
+
+    match rank {
+        Rank::Ace => HighCardClass::Ace,
+        Rank::Ten | Rank::Jack | Rank::Queen | Rank::King => HighCardClass::Broadway,
+        Rank::Seven | Rank::Eight | Rank::Nine => HighCardClass::Middle,
+        _ => HighCardClass::Low,
+    }
+}
+
+fn flushes(board: &Board) -> Vec<Suit> {
+    Suit::ALL
+        .iter()
+        .copied()
+        .filter(|suit| board.0.iter().filter(|card| card.1 == *suit).count() >= 3)
+        .collect()
+}
+
+fn straights(board: &Board) -> Vec<Rank> {
+    let wheel = vec![Rank::Ace, Rank::Two, Rank::Three, Rank::Four, Rank::Five];
+
+    std::iter::once(wheel)
+        .chain(Rank::ALL.windows(5).map(|window| window.to_vec()))
+        .filter(|window| {
+            window
+                .iter()
+                .filter(|rank| board.0.iter().any(|card| card.0 == **rank))
+                .count()
+                >= 3
+        })
+        .map(|window| window[0])
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::card::Rank;
+    use crate::card::Suit;
+    use crate::game::texture::HighCardClass;
+    use crate::game::texture::Pairing;
+    use crate::game::texture::Suitedness;
+    use crate::game::Board;
+
+    #[test]
+    fn test_pairing() {
+        let pairing = |s| Board::from_str(s).unwrap().texture().pairing;
+
+        assert_eq!(pairing("Ad7c2h"), Pairing::Unpaired);
+        assert_eq!(pairing("AdAc2h"), Pairing::Paired);
+        assert_eq!(pairing("AdAc2h2s"), Pairing::TwoPaired);
+        assert_eq!(pairing("AdAcAh"), Pairing::Trips);
+        assert_eq!(pairing("AdAcAh2s2d"), Pairing::FullHouse);
+        assert_eq!(pairing("AdAcAhAs2d"), Pairing::Quads);
+    }
+
+    #[test]
+    fn test_suitedness() {
+        let suitedness = |s| Board::from_str(s).unwrap().texture().suitedness;
+
+        assert_eq!(suitedness("Ad7c2h"), Suitedness::Rainbow);
+        assert_eq!(suitedness("Ad7d2h"), Suitedness::TwoTone);
+        assert_eq!(suitedness("Ad7d2d"), Suitedness::Monotone);
+        assert_eq!(suitedness("Ad7d2h3h"), Suitedness::TwoTone);
+        assert_eq!(suitedness("Ad7c2h3s"), Suitedness::Rainbow);
+        assert_eq!(suitedness("Ad7d2d3d"), Suitedness::Monotone);
+        assert_eq!(suitedness("AhKh7h2c3d"), Suitedness::FlushPossible);
+        assert_eq!(suitedness("AhKh7h2h3d"), Suitedness::FlushPossible);
+        assert_eq!(suitedness("AhKh7c2c3d"), Suitedness::TwoTone);
+    }
+
+    #[test]
+    fn test_high_card() {
+        let high_card = |s| Board::from_str(s).unwrap().texture().high_card;
+
+        assert_eq!(high_card("Ad7c2h"), HighCardClass::Ace);
+        assert_eq!(high_card("Kd7c2h"), HighCardClass::Broadway);
+        assert_eq!(high_card("9d7c2h"), HighCardClass::Middle);
+        assert_eq!(high_card("6d4c2h"), HighCardClass::Low);
+    }
+
+    #[test]
+    fn test_flushes() {
+        let texture = Board::from_str("Ad7d2dKh9h").unwrap().texture();
+        assert_eq!(texture.flushes, vec![Suit::Diamonds]);
+
+        let texture = Board::from_str("Ad7d2h").unwrap().texture();
+        assert!(texture.flushes.is_empty());
+    }
+
+    #[test]
+    fn test_straights() {
+        let texture = Board::from_str("Kd7c2h").unwrap().texture();
+        assert!(texture.straights.is_empty());
+        assert_eq!(texture.connectedness(), 0);
+
+        let texture = Board::from_str("Ad2c3h").unwrap().texture();
+        assert_eq!(texture.straights, vec![Rank::Ace]);
+
+        let texture = Board::from_str("9d8c7h").unwrap().texture();
+        assert_eq!(texture.straights, vec![Rank::Five, Rank::Six, Rank::Seven]);
+        assert_eq!(texture.connectedness(), 3);
+
+        let texture = Board::from_str("AdKcQh").unwrap().texture();
+        assert_eq!(texture.straights, vec![Rank::Ten]);
+    }
+}
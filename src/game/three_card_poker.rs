@@ -0,0 +1,196 @@
+//! Settling three-card poker against the dealer. Every player bets one unit
+//! on the ante and one on pair plus, and raises one more unit on the play
+//! bet with queen-six-four or better.
+
+use std::cmp::Ordering;
+
+use crate::card::Rank;
+use crate::game::HandOf3;
+use crate::three_card::ThreeCard;
+
+/// Units won per unit bet, keyed by `ThreeCard::category`; hands not listed
+/// pay nothing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Paytable {
+    /// Paid on the ante whenever the player plays, whatever the dealer holds.
+    pub ante_bonus: Vec<(&'static str, u32)>,
+    /// Paid on the pair plus bet, lost with anything lower.
+    pub pair_plus: Vec<(&'static str, u32)>,
+}
+
+impl Default for Paytable {
+    fn default() -> Self {
+        Self {
+            ante_bonus: vec![
+                ("straight_flush", 5),
+                ("three_of_a_kind", 4),
+                ("straight", 1),
+            ],
+            pair_plus: vec![
+                ("straight_flush", 40),
+                ("three_of_a_kind", 30),
+                ("straight", 6),
+                ("flush", 3),
+                ("pair", 1),
+            ],
+        }
+    }
+}
+
+/// Units won, negative when lost, on each bet of one player.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize))]
+pub struct Outcome {
+    pub plays: bool,
+    pub ante: i64,
+    pub play: i64,
+    pub pair_plus: i64,
+}
+
+impl Outcome {
+    pub fn total(&self) -> i64 {
+        self.ante + self.play + self.pair_plus
+    }
+}
+
+/// The dealer needs queen high or better for the play bets to be compared.
+pub fn qualifies(dealer: ThreeCard) -> bool {
+    match dealer {
+        ThreeCard::HighCard { ranks } => ranks[0] >= Rank::Queen,
+        _ => true,
+    }
+}
+
+/// Whether a player raises, queen-six-four or better.
+pub fn plays(hand: ThreeCard) -> bool {
+    match hand {
+        ThreeCard::HighCard { ranks } => ranks >= [Rank::Queen, Rank::Six, Rank::Four],
+        _ => true,
+    }
+}
+
+pub fn settle(dealer: HandOf3, hand: HandOf3, paytable: &Paytable) -> Outcome {
+    let dealer = ThreeCard::from_cards(dealer.0);
+    let hand = ThreeCard::from_cards(hand.0);
+
+    let pair_plus = match pays(&paytable.pair_plus, hand) {
+        0 => -1,
+        pay => pay as i64,
+    };
+
+    if !plays(hand) {
+        return Outcome {
+            plays: false,
+            ante: -1,
+            play: 0,
+            pair_plus,
+        };
+    }
+
+    let bonus = pays(&paytable.ante_bonus, hand) as i64;
+    // Without a qualifying dealer the ante wins and the play bet pushes.
+    let (ante, play) = match hand.cmp(&dealer) {
+        _ if !qualifies(dealer) => (1, 0),
+        Ordering::Greater => (1, 1),
+        Ordering::Less => (-1, -1),
+        Ordering::Equal => (0, 0),
+    };
+
+    Outcome {
+        plays: true,
+        ante: ante + bonus,
+        play,
+        pair_plus,
+    }
+}
+
+fn pays(table: &[(&'static str, u32)], hand: ThreeCard) -> u32 {
+    table
+        .iter()
+        .find(|(category, _)| *category == hand.category())
+        .map_or(0, |(_, pay)| *pay)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::game::three_card_poker::plays;
+    use crate::game::three_card_poker::qualifies;
+    use crate::game::three_card_poker::settle;
+    use crate::game::three_card_poker::Outcome;
+    use crate::game::three_card_poker::Paytable;
+    use crate::game::HandOf3;
+    use crate::three_card::ThreeCard;
+
+    fn hand(cards: &str) -> HandOf3 {
+        HandOf3::from_str(cards).unwrap()
+    }
+
+    fn three_card(cards: &str) -> ThreeCard {
+        ThreeCard::from_cards(hand(cards).0)
+    }
+
+    #[test]
+    fn test_qualifies_and_plays() {
+        assert!(qualifies(three_card("Qh7d2c")));
+        assert!(!qualifies(three_card("Jh9d8c")));
+        assert!(qualifies(three_card("2h2d3c")));
+
+        assert!(plays(three_card("Qh6d4c")));
+        assert!(!plays(three_card("Qh6d3c")));
+        assert!(plays(three_card("Kh3d2c")));
+    }
+
+    #[test]
+    fn test_settle() {
+        let paytable = Paytable::default();
+
+        // The dealer does not qualify: the ante wins, the play bet pushes.
+        assert_eq!(
+            settle(hand("Jh9d8c"), hand("AhKd3c"), &paytable),
+            Outcome {
+                plays: true,
+                ante: 1,
+                play: 0,
+                pair_plus: -1,
+            }
+        );
+
+        // A straight beats the dealer's flush and earns the ante bonus.
+        let outcome = settle(hand("Ah9h4h"), hand("5c6d7s"), &paytable);
+        assert_eq!(
+            outcome,
+            Outcome {
+                plays: true,
+                ante: 2,
+                play: 1,
+                pair_plus: 6,
+            }
+        );
+        assert_eq!(outcome.total(), 9);
+
+        assert_eq!(
+            settle(hand("KhKd4c"), hand("QsQc9d"), &paytable),
+            Outcome {
+                plays: true,
+                ante: -1,
+                play: -1,
+                pair_plus: 1,
+            }
+        );
+        assert_eq!(
+            settle(hand("AhKd9c"), hand("AsKc9d"), &paytable).total(),
+            -1
+        );
+        assert_eq!(
+            settle(hand("AhKd9c"), hand("Js8c4d"), &paytable),
+            Outcome {
+                plays: false,
+                ante: -1,
+                play: 0,
+                pair_plus: -1,
+            }
+        );
+    }
+}
@@ -0,0 +1,377 @@
+use std::fmt;
+use std::io;
+
+use ::itertools::Itertools;
+
+use crate::game::Game;
+use crate::history::invalid;
+use crate::history::Amount;
+use crate::history::HandHistory;
+use crate::payout::OddChip;
+use crate::payout::Player;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Finding {
+    /// The actions do not add up to the pot the site declares.
+    Pot { declared: Amount, computed: Amount },
+    /// A player was awarded a different amount than the evaluator gives.
+    Award {
+        player: String,
+        declared: Amount,
+        computed: Amount,
+    },
+}
+
+impl fmt::Display for Finding {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Pot { declared, computed } => write!(
+                f,
+                "pot declared {}, computed {}",
+                format_amount(*declared),
+                format_amount(*computed)
+            ),
+            Self::Award {
+                player,
+                declared,
+                computed,
+            } => write!(
+                f,
+                "{} declared {}, computed {}",
+                player,
+                format_amount(*declared),
+                format_amount(*computed)
+            ),
+        }
+    }
+}
+
+pub fn format_amount(amount: Amount) -> String {
+    format!("{}.{:02}", amount / 100, amount % 100)
+}
+
+/// Recomputes the showdown and the pot distribution of `hand` and lists
+/// every disagreement with the declared result. Fails when the hand cannot
+/// be checked, e.g. because a player at showdown never revealed their cards.
+pub fn audit(hand: &HandHistory) -> io::Result<Vec<Finding>> {
+    let mut findings = vec![];
+
+    let computed_pot = hand.contributions().iter().map(|(_, c)| c).sum();
+    if computed_pot != hand.total_pot {
+        findings.push(Finding::Pot {
+            declared: hand.total_pot,
+            computed: computed_pot,
+        });
+    }
+
+    for (player, computed) in awards(hand)? {
+        let declared = hand
+            .winners
+            .iter()
+            .find(|(name, _)| *name == player)
+            .map_or(0, |(_, amount)| *amount);
+
+        if declared != computed {
+            findings.push(Finding::Award {
+                player,
+                declared,
+                computed,
+            });
+        }
+    }
+
+    Ok(findings)
+}
+
+/// A copy of `hand` with the declared winners replaced by the evaluator's.
+pub fn settle(hand: &HandHistory) -> io::Result<HandHistory> {
+    let winners = awards(hand)?
+        .into_iter()
+        .filter(|(_, amount)| *amount > 0)
+        .collect();
+
+    Ok(HandHistory {
+        winners,
+        ..hand.clone()
+    })
+}
+
+/// What every seated player wins once the rake is taken, seat by seat.
+pub fn awards(hand: &HandHistory) -> io::Result<Vec<(String, Amount)>> {
+    let contributions = hand.contributions();
+    let players = hand
+        .seats
+        .iter()
+        .zip(contributions.iter())
+        .map(|(seat, (_, contributed))| {
+            let acted = hand.actions.iter().any(|a| a.player == seat.name);
+
+            Player {
+                contributed: *contributed,
+                folded: !acted || hand.folded(&seat.name),
+                cards: hand
+                    .shown
+                    .iter()
+                    .find(|(name, _)| *name == seat.name)
+                    .map(|(_, cards)| cards.clone())
+                    .unwrap_or_default(),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let ranking = ranking(hand, &players)?;
+
+    let mut pots = crate::payout::pots(&players);
+    let mut rake = hand.rake;
+    for pot in pots.iter_mut() {
+        let taken = rake.min(pot.amount);
+        pot.amount -= taken;
+        rake -= taken;
+    }
+
+    // Odd chips go to the first winner left of the button; with the button
+    // on an empty seat the player right of it stands in.
+    let button = hand
+        .seats
+        .iter()
+        .rposition(|seat| seat.number <= hand.button)
+        .unwrap_or(hand.seats.len() - 1);
+
+    let computed = crate::payout::split(&pots, &players, &ranking, button, OddChip::LeftOfButton);
+
+    Ok(hand
+        .seats
+        .iter()
+        .map(|seat| seat.name.clone())
+        .zip(computed)
+        .collect())
+}
+
+/// Groups of seat indices from the best hand to the worst.
+fn ranking(hand: &HandHistory, players: &[Player]) -> io::Result<Vec<Vec<usize>>> {
+    let remaining = (0..players.len())
+        .filter(|i| !players[*i].folded)
+        .collect::<Vec<_>>();
+
+    if remaining.len() < 2 {
+        return Ok(vec![remaining]);
+    }
+
+    for i in &remaining {
+        if players[*i].cards.is_empty() {
+            return Err(invalid(format!(
+                "hand {}: cards of {} are unknown",
+                hand.id, hand.seats[*i].name
+            )));
+        }
+    }
+
+    let game = hand
+        .game()
+        .ok_or_else(|| invalid(format!("hand {}: showdown cannot be evaluated", hand.id)))?;
+
+    let ranked_hands = Game::rank_hands(game);
+    let grouped_hands = Game::group_hands(ranked_hands);
+    let sorted_hands = Game::sort_hands(grouped_hands);
+
+    Ok(sorted_hands
+        .into_iter()
+        .rev()
+        .map(|group| {
+            group
+                .into_iter()
+                .filter_map(|named| {
+                    remaining
+                        .iter()
+                        .copied()
+                        .find(|i| named.name.as_ref() == Some(&hand.seats[*i].name))
+                })
+                .sorted()
+                .collect()
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::history::audit::audit;
+    use crate::history::audit::settle;
+    use crate::history::audit::Finding;
+    use crate::history::pokerstars::parse;
+
+    const SHOWDOWN: &str = "\
+PokerStars Hand #200001: Hold'em No Limit ($0.05/$0.10 USD) - 2020/01/01 12:00:00 ET
+Table 'Beta' 6-max Seat #3 is the button
+Seat 1: alice ($10 in chips)
+Seat 2: bob ($10 in chips)
+Seat 3: carol ($10 in chips)
+alice: posts small blind $0.05
+bob: posts big blind $0.10
+*** HOLE CARDS ***
+carol: calls $0.10
+alice: calls $0.05
+bob: checks
+*** FLOP *** [Qs Jh 2d]
+alice: bets $0.30
+bob: calls $0.30
+carol: calls $0.30
+*** TURN *** [Qs Jh 2d] [7c]
+alice: checks
+bob: checks
+carol: checks
+*** RIVER *** [Qs Jh 2d 7c] [3h]
+alice: checks
+bob: checks
+carol: checks
+*** SHOW DOWN ***
+alice: shows [Ah Kh] (high card Ace)
+bob: shows [As Kd] (high card Ace)
+carol: shows [Qd 9d] (a pair of Queens)
+carol collected $1.17 from pot
+*** SUMMARY ***
+Total pot $1.20 | Rake $0.03
+Board [Qs Jh 2d 7c 3h]
+";
+
+    #[test]
+    fn test_audit_agrees() {
+        assert_eq!(audit(&parse(SHOWDOWN).unwrap()).unwrap(), vec![]);
+    }
+
+    #[test]
+    fn test_audit_wrong_winner() {
+        let text = SHOWDOWN.replace("carol collected", "alice collected");
+
+        assert_eq!(
+            audit(&parse(&text).unwrap()).unwrap(),
+            vec![
+                Finding::Award {
+                    player: "alice".to_string(),
+                    declared: 117,
+                    computed: 0,
+                },
+                Finding::Award {
+                    player: "carol".to_string(),
+                    declared: 0,
+                    computed: 117,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_audit_kickers() {
+        // Both hands are ace high, alice wins on the king kicker.
+        let text = SHOWDOWN
+            .replace("[As Kd] (high card Ace)", "[As Td] (high card Ace)")
+            .replace("[Qd 9d] (a pair of Queens)", "[9c 8c] (high card Queen)")
+            .replace("carol collected", "alice collected");
+        assert_eq!(audit(&parse(&text).unwrap()).unwrap(), vec![]);
+
+        let text = text.replace(
+            "alice collected $1.17 from pot",
+            "alice collected $0.59 from pot\nbob collected $0.58 from pot",
+        );
+        assert_eq!(
+            audit(&parse(&text).unwrap()).unwrap(),
+            vec![
+                Finding::Award {
+                    player: "alice".to_string(),
+                    declared: 59,
+                    computed: 117,
+                },
+                Finding::Award {
+                    player: "bob".to_string(),
+                    declared: 58,
+                    computed: 0,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_settle() {
+        let text = SHOWDOWN.replace("carol collected", "alice collected");
+        let hand = settle(&parse(&text).unwrap()).unwrap();
+
+        assert_eq!(hand.winners, vec![("carol".to_string(), 117)]);
+        assert_eq!(audit(&hand).unwrap(), vec![]);
+    }
+
+    #[test]
+    fn test_audit_split_pot() {
+        let text = SHOWDOWN
+            .replace("[Qd 9d] (a pair of Queens)", "[9c 8c] (high card Queen)")
+            .replace(
+                "carol collected $1.17 from pot",
+                "alice collected $0.58 from pot\nbob collected $0.59 from pot",
+            );
+        let findings = audit(&parse(&text).unwrap()).unwrap();
+
+        // The odd chip goes to alice, first to the left of the button.
+        assert_eq!(
+            findings,
+            vec![
+                Finding::Award {
+                    player: "alice".to_string(),
+                    declared: 58,
+                    computed: 59,
+                },
+                Finding::Award {
+                    player: "bob".to_string(),
+                    declared: 59,
+                    computed: 58,
+                },
+            ]
+        );
+        assert_eq!(
+            findings[0].to_string(),
+            "alice declared 0.58, computed 0.59"
+        );
+    }
+
+    #[test]
+    fn test_audit_pot_mismatch() {
+        let text = SHOWDOWN
+            .replace("Total pot $1.20", "Total pot $1.30")
+            .replace("$1.17", "$1.27");
+
+        assert_eq!(
+            audit(&parse(&text).unwrap()).unwrap()[0],
+            Finding::Pot {
+                declared: 130,
+                computed: 120,
+            }
+        );
+    }
+
+    #[test]
+    fn test_audit_uncontested() {
+        let text = "\
+PokerStars Hand #200002: Hold'em No Limit ($0.05/$0.10 USD) - 2020/01/01 12:01:00 ET
+Table 'Beta' 6-max Seat #1 is the button
+Seat 1: alice ($10 in chips)
+Seat 2: bob ($10 in chips)
+Seat 4: dave ($10 in chips) is sitting out
+alice: posts small blind $0.05
+bob: posts big blind $0.10
+*** HOLE CARDS ***
+alice: raises $0.20 to $0.30
+bob: folds
+Uncalled bet ($0.20) returned to alice
+alice collected $0.20 from pot
+alice: doesn't show hand
+*** SUMMARY ***
+Total pot $0.20 | Rake $0
+";
+
+        assert_eq!(audit(&parse(text).unwrap()).unwrap(), vec![]);
+    }
+
+    #[test]
+    fn test_audit_unknown_cards() {
+        let text = SHOWDOWN.replace("bob: shows [As Kd] (high card Ace)\n", "bob: mucks hand\n");
+
+        assert!(audit(&parse(&text).unwrap()).is_err());
+    }
+}
@@ -0,0 +1,168 @@
+pub mod audit;
+#[cfg(feature = "ohh")]
+pub mod ohh;
+pub mod pokerstars;
+
+use std::convert::TryInto;
+use std::io;
+
+use crate::card::Card;
+use crate::engine::Limit;
+use crate::engine::Street;
+use crate::game::Board;
+use crate::game::Game;
+use crate::game::HandOf2;
+use crate::game::HandOf4;
+use crate::game::Kind;
+use crate::game::Named;
+
+/// Chip amounts are kept in hundredths so that cash games (`$0.25`) and
+/// tournaments (`1500`) share one integer representation.
+pub type Amount = u64;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Seat {
+    pub number: u8,
+    pub name: String,
+    pub stack: Amount,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Action {
+    Ante(Amount),
+    SmallBlind(Amount),
+    BigBlind(Amount),
+    /// Small and big blind posted together, the small blind being dead.
+    Blinds(Amount),
+    Fold,
+    Check,
+    Call(Amount),
+    Bet(Amount),
+    Raise {
+        by: Amount,
+        to: Amount,
+    },
+    /// Part of a bet nobody called, given back to the bettor.
+    Returned(Amount),
+    Show(Vec<Card>),
+    Muck,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlayerAction {
+    pub street: Street,
+    pub player: String,
+    pub action: Action,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HandHistory {
+    pub id: String,
+    pub tournament: Option<String>,
+    pub kind: Kind,
+    pub limit: Limit,
+    pub small_blind: Amount,
+    pub big_blind: Amount,
+    /// Seat number of the button.
+    pub button: u8,
+    pub seats: Vec<Seat>,
+    pub actions: Vec<PlayerAction>,
+    pub board: Vec<Card>,
+    /// Cards shown at showdown or revealed in the summary.
+    pub shown: Vec<(String, Vec<Card>)>,
+    /// Amounts the site declares each player collected.
+    pub winners: Vec<(String, Amount)>,
+    pub total_pot: Amount,
+    pub rake: Amount,
+}
+
+impl HandHistory {
+    pub fn folded(&self, player: &str) -> bool {
+        self.actions
+            .iter()
+            .any(|action| action.player == player && action.action == Action::Fold)
+    }
+
+    /// Chips each player put in the pot, uncalled bets already returned.
+    pub fn contributions(&self) -> Vec<(String, Amount)> {
+        self.seats
+            .iter()
+            .map(|seat| {
+                let mut committed = 0;
+                let mut total = 0;
+                let mut street = Street::Preflop;
+
+                for action in self.actions.iter().filter(|a| a.player == seat.name) {
+                    if action.street != street {
+                        total += committed;
+                        committed = 0;
+                        street = action.street;
+                    }
+
+                    match action.action {
+                        Action::Ante(amount) => total += amount,
+                        Action::SmallBlind(amount)
+                        | Action::BigBlind(amount)
+                        | Action::Call(amount)
+                        | Action::Bet(amount) => committed += amount,
+                        // The dead small blind does not count towards calling.
+                        Action::Blinds(amount) => {
+                            total += amount - self.big_blind.min(amount);
+                            committed += self.big_blind.min(amount);
+                        }
+                        Action::Raise { to, .. } => committed = to,
+                        Action::Returned(amount) => committed -= amount.min(committed),
+                        _ => {}
+                    }
+                }
+
+                (seat.name.clone(), total + committed)
+            })
+            .collect()
+    }
+
+    /// The showdown between players who did not fold and whose cards are
+    /// known, every hand named after its player.
+    pub fn game(&self) -> Option<Game> {
+        if self.board.len() != 5 {
+            return None;
+        }
+
+        let shown = self
+            .shown
+            .iter()
+            .filter(|(player, _)| !self.folded(player))
+            .collect::<Vec<_>>();
+
+        if shown.is_empty() {
+            return None;
+        }
+
+        fn named<H, const N: usize>(
+            shown: &[&(String, Vec<Card>)],
+            hand: fn([Card; N]) -> H,
+        ) -> Option<Vec<Named<H>>> {
+            shown
+                .iter()
+                .map(|(player, cards)| {
+                    Some(Named {
+                        name: Some(player.clone()),
+                        hand: hand(cards.clone().try_into().ok()?),
+                    })
+                })
+                .collect()
+        }
+
+        let board = Board(self.board.clone());
+
+        match self.kind {
+            Kind::TexasHoldem => Some(Game::TexasHoldem(board, named(&shown, HandOf2)?)),
+            Kind::OmahaHoldem => Some(Game::OmahaHoldem(board, named(&shown, HandOf4)?)),
+            Kind::FiveCardDraw | Kind::ThreeCardPoker => None,
+        }
+    }
+}
+
+fn invalid(reason: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, reason)
+}
@@ -0,0 +1,691 @@
+//! The Open Hand History JSON standard (https://hh-specs.handhistory.org).
+
+use std::collections::HashMap;
+use std::io;
+
+use ::serde::Deserialize;
+use ::serde::Serialize;
+
+use crate::card::notation;
+use crate::card::Card;
+use crate::engine::Limit;
+use crate::engine::Street;
+use crate::game::Kind;
+use crate::history::invalid;
+use crate::history::Action;
+use crate::history::Amount;
+use crate::history::HandHistory;
+use crate::history::PlayerAction;
+use crate::history::Seat;
+
+const SPEC_VERSION: &str = "1.4.6";
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Document {
+    ohh: Ohh,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Ohh {
+    spec_version: String,
+    #[serde(default)]
+    site_name: String,
+    #[serde(default)]
+    network_name: String,
+    #[serde(default)]
+    internal_version: String,
+    #[serde(default)]
+    tournament: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    tournament_info: Option<TournamentInfo>,
+    game_number: String,
+    #[serde(default)]
+    start_date_utc: String,
+    #[serde(default)]
+    table_name: String,
+    game_type: String,
+    bet_limit: BetLimit,
+    #[serde(default)]
+    table_size: u8,
+    #[serde(default)]
+    currency: String,
+    dealer_seat: u8,
+    small_blind_amount: f64,
+    big_blind_amount: f64,
+    #[serde(default)]
+    ante_amount: f64,
+    players: Vec<OhhPlayer>,
+    rounds: Vec<Round>,
+    #[serde(default)]
+    pots: Vec<Pot>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct TournamentInfo {
+    tournament_number: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct BetLimit {
+    bet_type: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct OhhPlayer {
+    id: u32,
+    seat: u8,
+    name: String,
+    starting_stack: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Round {
+    id: u32,
+    street: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    cards: Vec<String>,
+    actions: Vec<OhhAction>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct OhhAction {
+    action_number: u32,
+    player_id: u32,
+    action: String,
+    #[serde(default)]
+    amount: f64,
+    #[serde(default)]
+    is_allin: bool,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    cards: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Pot {
+    number: u32,
+    amount: f64,
+    #[serde(default)]
+    rake: f64,
+    player_wins: Vec<PlayerWin>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct PlayerWin {
+    player_id: u32,
+    win_amount: f64,
+}
+
+/// Parses a file of OHH documents, one `{"ohh": ...}` object per hand.
+pub fn parse_all(text: &str) -> Vec<io::Result<HandHistory>> {
+    ::serde_json::Deserializer::from_str(text)
+        .into_iter::<Document>()
+        .map(|document| document.map_err(io::Error::from).and_then(from_ohh))
+        .collect()
+}
+
+pub fn parse(text: &str) -> io::Result<HandHistory> {
+    from_ohh(::serde_json::from_str::<Document>(text)?)
+}
+
+/// Fails for games the format has no `game_type` for.
+pub fn write(hand: &HandHistory) -> io::Result<String> {
+    Ok(::serde_json::to_string_pretty(&to_ohh(hand)?)?)
+}
+
+fn from_ohh(document: Document) -> io::Result<HandHistory> {
+    let ohh = document.ohh;
+
+    let kind = match ohh.game_type.as_str() {
+        "Holdem" => Kind::TexasHoldem,
+        "Omaha" => Kind::OmahaHoldem,
+        other => return Err(invalid(format!("unsupported game: {}", other))),
+    };
+    let limit = match ohh.bet_limit.bet_type.as_str() {
+        "NL" => Limit::NoLimit,
+        "PL" => Limit::PotLimit,
+        "FL" => Limit::FixedLimit,
+        other => return Err(invalid(format!("unknown betting limit: {}", other))),
+    };
+
+    let names = ohh
+        .players
+        .iter()
+        .map(|player| (player.id, player.name.clone()))
+        .collect::<HashMap<_, _>>();
+    let name = |id: u32| {
+        names
+            .get(&id)
+            .cloned()
+            .ok_or_else(|| invalid(format!("unknown player id: {}", id)))
+    };
+
+    let mut hand = HandHistory {
+        id: ohh.game_number.clone(),
+        tournament: ohh.tournament_info.map(|info| info.tournament_number),
+        kind,
+        limit,
+        small_blind: from_decimal(ohh.small_blind_amount)?,
+        big_blind: from_decimal(ohh.big_blind_amount)?,
+        button: ohh.dealer_seat,
+        seats: ohh
+            .players
+            .iter()
+            .map(|player| {
+                Ok(Seat {
+                    number: player.seat,
+                    name: player.name.clone(),
+                    stack: from_decimal(player.starting_stack)?,
+                })
+            })
+            .collect::<io::Result<_>>()?,
+        actions: vec![],
+        board: vec![],
+        shown: vec![],
+        winners: vec![],
+        total_pot: 0,
+        rake: 0,
+    };
+
+    for round in &ohh.rounds {
+        let street = match round.street.as_str() {
+            "Preflop" => Street::Preflop,
+            "Flop" => Street::Flop,
+            "Turn" => Street::Turn,
+            "River" | "Showdown" => Street::River,
+            other => return Err(invalid(format!("unknown street: {}", other))),
+        };
+
+        for card in &round.cards {
+            hand.board.push(parse_card(card)?);
+        }
+
+        for action in &round.actions {
+            let player = name(action.player_id)?;
+            let amount = from_decimal(action.amount)?;
+            let cards = action
+                .cards
+                .iter()
+                .map(|card| parse_card(card))
+                .collect::<io::Result<Vec<_>>>()?;
+
+            let action = match action.action.as_str() {
+                "Post Ante" => Action::Ante(amount),
+                "Post SB" => Action::SmallBlind(amount),
+                "Post BB" => Action::BigBlind(amount),
+                "Post Dead" => Action::Blinds(amount),
+                "Fold" => Action::Fold,
+                "Check" => Action::Check,
+                "Call" => Action::Call(amount),
+                "Bet" => Action::Bet(amount),
+                "Raise" => Action::Raise {
+                    by: amount.saturating_sub(current_bet(&hand, street)),
+                    to: amount,
+                },
+                "Shows Cards" => Action::Show(cards.clone()),
+                "Mucks Cards" => Action::Muck,
+                _ => continue,
+            };
+
+            if !cards.is_empty()
+                && matches!(action, Action::Show(_) | Action::Muck)
+                && !hand.shown.iter().any(|(name, _)| *name == player)
+            {
+                hand.shown.push((player.clone(), cards));
+            }
+
+            hand.actions.push(PlayerAction {
+                street,
+                player,
+                action,
+            });
+        }
+
+        if round.street != "Showdown" {
+            return_uncalled(&mut hand, street);
+        }
+    }
+
+    for pot in &ohh.pots {
+        hand.total_pot += from_decimal(pot.amount)?;
+        hand.rake += from_decimal(pot.rake)?;
+
+        for win in &pot.player_wins {
+            let player = name(win.player_id)?;
+            let amount = from_decimal(win.win_amount)?;
+
+            match hand.winners.iter_mut().find(|(name, _)| *name == player) {
+                Some((_, total)) => *total += amount,
+                None => hand.winners.push((player, amount)),
+            }
+        }
+    }
+
+    Ok(hand)
+}
+
+fn to_ohh(hand: &HandHistory) -> io::Result<Document> {
+    let game_type = match hand.kind {
+        Kind::TexasHoldem => "Holdem",
+        Kind::OmahaHoldem => "Omaha",
+        Kind::FiveCardDraw | Kind::ThreeCardPoker => {
+            return Err(invalid(format!("unsupported game: {}", hand.kind)))
+        }
+    };
+
+    let id = |player: &str| {
+        hand.seats
+            .iter()
+            .position(|seat| seat.name == player)
+            .map(|i| i as u32 + 1)
+            .ok_or_else(|| invalid(format!("unknown player: {}", player)))
+    };
+
+    let mut board = hand.board.iter();
+    let mut action_number = 0;
+    let mut rounds = vec![];
+
+    for (i, (street, name)) in [
+        (Street::Preflop, "Preflop"),
+        (Street::Flop, "Flop"),
+        (Street::Turn, "Turn"),
+        (Street::River, "River"),
+    ]
+    .iter()
+    .enumerate()
+    {
+        let dealt = match street {
+            Street::Preflop => 0,
+            Street::Flop => 3,
+            Street::Turn | Street::River => 1,
+        };
+        let cards = board
+            .by_ref()
+            .take(dealt)
+            .map(|card| card.to_string())
+            .collect::<Vec<_>>();
+
+        let actions = hand
+            .actions
+            .iter()
+            .filter(|action| action.street == *street)
+            .filter(|action| !matches!(action.action, Action::Show(_) | Action::Muck))
+            .map(|action| {
+                let (kind, amount) = match action.action {
+                    Action::Ante(amount) => ("Post Ante", amount),
+                    Action::SmallBlind(amount) => ("Post SB", amount),
+                    Action::BigBlind(amount) => ("Post BB", amount),
+                    Action::Blinds(amount) => ("Post Dead", amount),
+                    Action::Fold => ("Fold", 0),
+                    Action::Check => ("Check", 0),
+                    Action::Call(amount) => ("Call", amount),
+                    Action::Bet(amount) => ("Bet", amount),
+                    Action::Raise { to, .. } => ("Raise", to),
+                    // Uncalled bets are implied by the actions in OHH.
+                    Action::Returned(_) | Action::Show(_) | Action::Muck => return Ok(None),
+                };
+                action_number += 1;
+
+                Ok(Some(OhhAction {
+                    action_number,
+                    player_id: id(&action.player)?,
+                    action: kind.to_string(),
+                    amount: to_decimal(amount),
+                    is_allin: false,
+                    cards: vec![],
+                }))
+            })
+            .filter_map(Result::transpose)
+            .collect::<io::Result<Vec<_>>>()?;
+
+        if cards.is_empty() && actions.is_empty() && *street != Street::Preflop {
+            continue;
+        }
+
+        rounds.push(Round {
+            id: i as u32,
+            street: name.to_string(),
+            cards,
+            actions,
+        });
+    }
+
+    let showdown = hand
+        .actions
+        .iter()
+        .map(|action| {
+            let kind = match action.action {
+                Action::Show(_) => "Shows Cards",
+                Action::Muck => "Mucks Cards",
+                _ => return Ok(None),
+            };
+            let cards = hand
+                .shown
+                .iter()
+                .find(|(name, _)| *name == action.player)
+                .map(|(_, cards)| cards.iter().map(|card| card.to_string()).collect())
+                .unwrap_or_default();
+            action_number += 1;
+
+            Ok(Some(OhhAction {
+                action_number,
+                player_id: id(&action.player)?,
+                action: kind.to_string(),
+                amount: 0.0,
+                is_allin: false,
+                cards,
+            }))
+        })
+        .filter_map(Result::transpose)
+        .collect::<io::Result<Vec<_>>>()?;
+
+    if !showdown.is_empty() {
+        rounds.push(Round {
+            id: rounds.len() as u32,
+            street: "Showdown".to_string(),
+            cards: vec![],
+            actions: showdown,
+        });
+    }
+
+    let player_wins = hand
+        .winners
+        .iter()
+        .map(|(player, amount)| {
+            Ok(PlayerWin {
+                player_id: id(player)?,
+                win_amount: to_decimal(*amount),
+            })
+        })
+        .collect::<io::Result<Vec<_>>>()?;
+
+    let ante = hand
+        .actions
+        .iter()
+        .find_map(|action| match action.action {
+            Action::Ante(amount) => Some(amount),
+            _ => None,
+        })
+        .unwrap_or(0);
+
+    Ok(Document {
+        ohh: Ohh {
+            spec_version: SPEC_VERSION.to_string(),
+            site_name: String::new(),
+            network_name: String::new(),
+            internal_version: String::new(),
+            tournament: hand.tournament.is_some(),
+            tournament_info: hand
+                .tournament
+                .clone()
+                .map(|tournament_number| TournamentInfo { tournament_number }),
+            game_number: hand.id.clone(),
+            start_date_utc: String::new(),
+            table_name: String::new(),
+            game_type: game_type.to_string(),
+            bet_limit: BetLimit {
+                bet_type: match hand.limit {
+                    Limit::NoLimit => "NL",
+                    Limit::PotLimit => "PL",
+                    Limit::FixedLimit => "FL",
+                }
+                .to_string(),
+            },
+            table_size: hand.seats.len() as u8,
+            currency: String::new(),
+            dealer_seat: hand.button,
+            small_blind_amount: to_decimal(hand.small_blind),
+            big_blind_amount: to_decimal(hand.big_blind),
+            ante_amount: to_decimal(ante),
+            players: hand
+                .seats
+                .iter()
+                .enumerate()
+                .map(|(i, seat)| OhhPlayer {
+                    id: i as u32 + 1,
+                    seat: seat.number,
+                    name: seat.name.clone(),
+                    starting_stack: to_decimal(seat.stack),
+                })
+                .collect(),
+            rounds,
+            pots: vec![Pot {
+                number: 0,
+                amount: to_decimal(hand.total_pot),
+                rake: to_decimal(hand.rake),
+                player_wins,
+            }],
+        },
+    })
+}
+
+/// The highest amount anyone has put in on `street` so far.
+fn current_bet(hand: &HandHistory, street: Street) -> Amount {
+    committed(hand, street).values().copied().max().unwrap_or(0)
+}
+
+fn committed(hand: &HandHistory, street: Street) -> HashMap<String, Amount> {
+    let mut committed = HashMap::new();
+
+    for action in hand.actions.iter().filter(|a| a.street == street) {
+        let chips = committed.entry(action.player.clone()).or_insert(0);
+
+        match action.action {
+            Action::SmallBlind(amount) | Action::Call(amount) | Action::Bet(amount) => {
+                *chips += amount
+            }
+            Action::BigBlind(amount) | Action::Blinds(amount) => {
+                *chips += hand.big_blind.min(amount)
+            }
+            Action::Raise { to, .. } => *chips = to,
+            Action::Returned(amount) => *chips -= amount.min(*chips),
+            _ => {}
+        }
+    }
+
+    committed
+}
+
+/// OHH leaves uncalled bets implicit; they are made explicit the way
+/// PokerStars reports them so that contributions add up.
+fn return_uncalled(hand: &mut HandHistory, street: Street) {
+    let committed = committed(hand, street);
+    let mut amounts = committed.iter().collect::<Vec<_>>();
+    amounts.sort_by_key(|(_, amount)| std::cmp::Reverse(**amount));
+
+    if let [(player, highest), rest @ ..] = amounts.as_slice() {
+        let called = rest.first().map_or(0, |(_, amount)| **amount);
+
+        if **highest > called {
+            hand.actions.push(PlayerAction {
+                street,
+                player: (*player).clone(),
+                action: Action::Returned(**highest - called),
+            });
+        }
+    }
+}
+
+fn from_decimal(amount: f64) -> io::Result<Amount> {
+    if !amount.is_finite() || amount < 0.0 {
+        return Err(invalid(format!("invalid amount: {}", amount)));
+    }
+
+    Ok((amount * 100.0).round() as Amount)
+}
+
+fn to_decimal(amount: Amount) -> f64 {
+    amount as f64 / 100.0
+}
+
+fn parse_card(raw: &str) -> io::Result<Card> {
+    notation::card(raw).ok_or_else(|| invalid(format!("invalid card: {}", raw)))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::card::parse_cards;
+    use crate::engine::Street;
+    use crate::game::Kind;
+    use crate::history::audit::audit;
+    use crate::history::ohh::parse;
+    use crate::history::ohh::parse_all;
+    use crate::history::ohh::write;
+    use crate::history::Action;
+    use crate::history::HandHistory;
+    use crate::history::PlayerAction;
+
+    const OHH: &str = r#"{
+  "ohh": {
+    "spec_version": "1.4.6",
+    "site_name": "Example",
+    "game_number": "42",
+    "game_type": "Holdem",
+    "bet_limit": { "bet_type": "NL", "bet_cap": 0 },
+    "table_size": 6,
+    "dealer_seat": 1,
+    "small_blind_amount": 1,
+    "big_blind_amount": 2,
+    "ante_amount": 0,
+    "hero_player_id": 1,
+    "players": [
+      { "id": 1, "seat": 1, "name": "alice", "display": "alice", "starting_stack": 56 },
+      { "id": 2, "seat": 2, "name": "bob", "display": "bob", "starting_stack": 200 }
+    ],
+    "rounds": [
+      {
+        "id": 0,
+        "street": "Preflop",
+        "actions": [
+          { "action_number": 1, "player_id": 1, "action": "Post SB", "amount": 1 },
+          { "action_number": 2, "player_id": 2, "action": "Post BB", "amount": 2 },
+          { "action_number": 3, "player_id": 1, "action": "Dealt Cards", "cards": ["Ah", "Kd"] },
+          { "action_number": 4, "player_id": 1, "action": "Raise", "amount": 6 },
+          { "action_number": 5, "player_id": 2, "action": "Call", "amount": 4 }
+        ]
+      },
+      {
+        "id": 1,
+        "street": "Flop",
+        "cards": ["2c", "5s", "9h"],
+        "actions": [
+          { "action_number": 6, "player_id": 2, "action": "Check" },
+          { "action_number": 7, "player_id": 1, "action": "Bet", "amount": 8 },
+          { "action_number": 8, "player_id": 2, "action": "Raise", "amount": 30 },
+          { "action_number": 9, "player_id": 1, "action": "Call", "amount": 22 }
+        ]
+      },
+      { "id": 2, "street": "Turn", "cards": ["Jd"], "actions": [] },
+      {
+        "id": 3,
+        "street": "River",
+        "cards": ["3s"],
+        "actions": [
+          { "action_number": 10, "player_id": 2, "action": "Bet", "amount": 50 },
+          { "action_number": 11, "player_id": 1, "action": "Call", "amount": 20, "is_allin": true }
+        ]
+      },
+      {
+        "id": 4,
+        "street": "Showdown",
+        "actions": [
+          { "action_number": 12, "player_id": 2, "action": "Shows Cards", "cards": ["Kh", "Kc"] },
+          { "action_number": 13, "player_id": 1, "action": "Shows Cards", "cards": ["Ah", "Kd"] }
+        ]
+      }
+    ],
+    "pots": [
+      { "number": 0, "amount": 112, "rake": 0, "player_wins": [{ "player_id": 2, "win_amount": 112 }] }
+    ]
+  }
+}"#;
+
+    #[test]
+    fn test_parse() {
+        let hand = parse(OHH).unwrap();
+
+        assert_eq!(hand.id, "42");
+        assert_eq!((hand.small_blind, hand.big_blind), (100, 200));
+        assert_eq!(hand.board, parse_cards("2c5s9hJd3s"));
+        assert_eq!(hand.shown[0], ("bob".to_string(), parse_cards("KhKc")));
+        assert_eq!(hand.winners, vec![("bob".to_string(), 11200)]);
+        assert!(hand.actions.contains(&PlayerAction {
+            street: Street::Flop,
+            player: "bob".to_string(),
+            action: Action::Raise { by: 2200, to: 3000 },
+        }));
+        // Only 20 of bob's river bet could be called.
+        assert!(hand.actions.contains(&PlayerAction {
+            street: Street::River,
+            player: "bob".to_string(),
+            action: Action::Returned(3000),
+        }));
+        assert_eq!(audit(&hand).unwrap(), vec![]);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let hand = parse(OHH).unwrap();
+        assert_eq!(parse(&write(&hand).unwrap()).unwrap(), hand);
+
+        for text in &[
+            include_str!("fixtures/pokerstars-cash.txt"),
+            include_str!("fixtures/pokerstars-tournament.txt"),
+        ] {
+            let hand = crate::history::pokerstars::parse(text).unwrap();
+            assert_eq!(parse(&write(&hand).unwrap()).unwrap(), hand);
+        }
+    }
+
+    #[test]
+    fn test_write_every_kind() {
+        for &kind in &[
+            Kind::TexasHoldem,
+            Kind::OmahaHoldem,
+            Kind::FiveCardDraw,
+            Kind::ThreeCardPoker,
+        ] {
+            let hand = HandHistory {
+                kind,
+                ..parse(OHH).unwrap()
+            };
+
+            match kind {
+                Kind::TexasHoldem | Kind::OmahaHoldem => {
+                    assert_eq!(parse(&write(&hand).unwrap()).unwrap(), hand)
+                }
+                Kind::FiveCardDraw | Kind::ThreeCardPoker => assert!(write(&hand).is_err()),
+            }
+        }
+    }
+
+    #[test]
+    fn test_write_unknown_player() {
+        let mut hand = parse(OHH).unwrap();
+        hand.winners[0].0 = "Nobody".to_string();
+
+        let error = write(&hand).unwrap_err();
+        assert_eq!(error.to_string(), "unknown player: Nobody");
+
+        let mut hand = parse(OHH).unwrap();
+        hand.actions[0].player = "Nobody".to_string();
+
+        assert!(write(&hand).is_err());
+    }
+
+    #[test]
+    fn test_parse_all() {
+        let text = format!("{}\n\n{}\n", OHH, OHH.replace("\"42\"", "\"43\""));
+        let hands = parse_all(&text);
+
+        assert_eq!(hands.len(), 2);
+        assert_eq!(hands[1].as_ref().unwrap().id, "43");
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert!(parse("{}").is_err());
+        assert!(parse(&OHH.replace("\"Holdem\"", "\"OmahaHiLo\"")).is_err());
+        assert!(parse(&OHH.replace("\"Kh\"", "\"Kx\"")).is_err());
+        assert!(parse(&OHH.replace("\"player_id\": 2", "\"player_id\": 7")).is_err());
+    }
+}
@@ -0,0 +1,499 @@
+use std::io;
+
+use crate::card::notation;
+use crate::card::Card;
+use crate::engine::Limit;
+use crate::engine::Street;
+use crate::game::Kind;
+use crate::history::invalid;
+use crate::history::Action;
+use crate::history::Amount;
+use crate::history::HandHistory;
+use crate::history::PlayerAction;
+use crate::history::Seat;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Section {
+    Header,
+    Street(Street),
+    Showdown,
+    Summary,
+}
+
+/// Splits a file of PokerStars histories into hands and parses each one,
+/// so that a single malformed hand does not stop the rest of the import.
+pub fn parse_all(text: &str) -> Vec<io::Result<HandHistory>> {
+    let mut hands: Vec<Vec<&str>> = vec![];
+
+    for line in text.lines() {
+        let line = line.trim_start_matches('\u{feff}');
+
+        if line.starts_with("PokerStars ") {
+            hands.push(vec![]);
+        }
+        if let Some(hand) = hands.last_mut() {
+            hand.push(line);
+        }
+    }
+
+    hands.iter().map(|hand| parse(&hand.join("\n"))).collect()
+}
+
+pub fn parse(text: &str) -> io::Result<HandHistory> {
+    let mut lines = text
+        .lines()
+        .map(|line| line.trim_start_matches('\u{feff}').trim())
+        .filter(|line| !line.is_empty());
+
+    let header = lines
+        .next()
+        .ok_or_else(|| invalid("empty hand history".to_string()))?;
+
+    let mut hand = parse_header(header)?;
+    let mut section = Section::Header;
+
+    for line in lines {
+        if let Some(marker) = line.strip_prefix("*** ") {
+            section = match marker.split(" ***").next().unwrap_or("") {
+                "HOLE CARDS" => Section::Street(Street::Preflop),
+                "FLOP" => Section::Street(Street::Flop),
+                "TURN" => Section::Street(Street::Turn),
+                "RIVER" => Section::Street(Street::River),
+                "SHOW DOWN" => Section::Showdown,
+                "SUMMARY" => Section::Summary,
+                other => return Err(invalid(format!("unsupported section: {}", other))),
+            };
+
+            if let Section::Street(Street::Flop | Street::Turn | Street::River) = section {
+                let dealt = line
+                    .rfind('[')
+                    .ok_or_else(|| invalid(format!("missing board cards: {}", line)))?;
+                hand.board.extend(parse_bracketed(&line[dealt..])?);
+            }
+            continue;
+        }
+
+        if let Some(table) = line.strip_prefix("Table ") {
+            let button = table
+                .split("Seat #")
+                .nth(1)
+                .and_then(|rest| rest.split_whitespace().next())
+                .ok_or_else(|| invalid(format!("missing button: {}", line)))?;
+            hand.button = button
+                .parse()
+                .map_err(|_| invalid(format!("invalid button seat: {}", button)))?;
+            continue;
+        }
+
+        if line.starts_with("Seat ") && line.contains(": ") {
+            if section == Section::Summary {
+                parse_summary_seat(&mut hand, line)?;
+            } else if section == Section::Header {
+                hand.seats.push(parse_seat(line)?);
+            }
+            continue;
+        }
+
+        if let Some(pot) = line.strip_prefix("Total pot ") {
+            hand.total_pot = parse_amount(pot.split_whitespace().next().unwrap_or(""))?;
+            if let Some(rake) = pot.split("Rake ").nth(1) {
+                hand.rake = parse_amount(rake.split_whitespace().next().unwrap_or(""))?;
+            }
+            continue;
+        }
+
+        if let Some(board) = line.strip_prefix("Board ") {
+            if hand.board.is_empty() {
+                hand.board = parse_bracketed(board)?;
+            }
+            continue;
+        }
+
+        if let Some(uncalled) = line.strip_prefix("Uncalled bet (") {
+            let (amount, player) = uncalled
+                .split_once(") returned to ")
+                .ok_or_else(|| invalid(format!("unexpected line: {}", line)))?;
+            hand.actions.push(PlayerAction {
+                street: street_of(section),
+                player: player.to_string(),
+                action: Action::Returned(parse_amount(amount)?),
+            });
+            continue;
+        }
+
+        if let Some((player, amount)) = parse_collected(&hand, line) {
+            let amount = parse_amount(amount)?;
+            match hand.winners.iter_mut().find(|(name, _)| name == player) {
+                Some((_, total)) => *total += amount,
+                None => hand.winners.push((player.to_string(), amount)),
+            }
+            continue;
+        }
+
+        if let Some((player, action)) = split_action(&hand, line) {
+            if let Some(action) = parse_action(action)? {
+                if let Action::Show(cards) = &action {
+                    add_shown(&mut hand, player, cards.clone());
+                }
+                hand.actions.push(PlayerAction {
+                    street: street_of(section),
+                    player: player.to_string(),
+                    action,
+                });
+            }
+        }
+    }
+
+    if hand.seats.is_empty() {
+        return Err(invalid(format!("hand {} has no seats", hand.id)));
+    }
+    if ![0, 3, 4, 5].contains(&hand.board.len()) {
+        return Err(invalid(format!(
+            "hand {} has {} board cards",
+            hand.id,
+            hand.board.len()
+        )));
+    }
+
+    Ok(hand)
+}
+
+fn parse_header(line: &str) -> io::Result<HandHistory> {
+    let id = line
+        .split("Hand #")
+        .nth(1)
+        .and_then(|rest| rest.split(':').next())
+        .ok_or_else(|| invalid(format!("not a PokerStars hand: {}", line)))?;
+
+    let tournament = line
+        .split("Tournament #")
+        .nth(1)
+        .and_then(|rest| rest.split(',').next())
+        .map(|id| id.trim().to_string());
+
+    let kind = if line.contains("Hi/Lo") {
+        return Err(invalid(format!("unsupported game: {}", line)));
+    } else if line.contains("Hold'em") {
+        Kind::TexasHoldem
+    } else if line.contains("Omaha") {
+        Kind::OmahaHoldem
+    } else {
+        return Err(invalid(format!("unsupported game: {}", line)));
+    };
+
+    let limit = if line.contains("No Limit") {
+        Limit::NoLimit
+    } else if line.contains("Pot Limit") {
+        Limit::PotLimit
+    } else if line.contains("Limit") {
+        Limit::FixedLimit
+    } else {
+        return Err(invalid(format!("unknown betting limit: {}", line)));
+    };
+
+    // "($0.01/$0.02 USD)" in cash games, "Level I (10/20)" in tournaments.
+    let blinds = line
+        .split('(')
+        .skip(1)
+        .filter_map(|group| group.split(')').next())
+        .find(|group| group.contains('/'))
+        .ok_or_else(|| invalid(format!("missing blinds: {}", line)))?;
+    let (small_blind, big_blind) = blinds.split_once('/').unwrap();
+
+    Ok(HandHistory {
+        id: id.trim().to_string(),
+        tournament,
+        kind,
+        limit,
+        small_blind: parse_amount(small_blind)?,
+        big_blind: parse_amount(big_blind.split_whitespace().next().unwrap_or(""))?,
+        button: 0,
+        seats: vec![],
+        actions: vec![],
+        board: vec![],
+        shown: vec![],
+        winners: vec![],
+        total_pot: 0,
+        rake: 0,
+    })
+}
+
+fn parse_seat(line: &str) -> io::Result<Seat> {
+    let (number, rest) = line["Seat ".len()..].split_once(": ").unwrap();
+    let number = number
+        .parse()
+        .map_err(|_| invalid(format!("invalid seat number: {}", line)))?;
+
+    let chips = rest
+        .find(" in chips")
+        .ok_or_else(|| invalid(format!("missing stack: {}", line)))?;
+    let open = rest[..chips]
+        .rfind(" (")
+        .ok_or_else(|| invalid(format!("missing stack: {}", line)))?;
+
+    Ok(Seat {
+        number,
+        name: rest[..open].to_string(),
+        stack: parse_amount(&rest[open + 2..chips])?,
+    })
+}
+
+/// Summary lines reveal the cards of players who mucked at showdown.
+fn parse_summary_seat(hand: &mut HandHistory, line: &str) -> io::Result<()> {
+    let rest = line.split_once(": ").unwrap().1;
+    let player = match seated_prefix(hand, rest) {
+        Some(player) => player.to_string(),
+        None => return Ok(()),
+    };
+
+    for marker in &[" showed [", " mucked ["] {
+        if let Some(start) = rest.find(marker) {
+            let cards = parse_bracketed(&rest[start + marker.len() - 1..])?;
+            add_shown(hand, &player, cards);
+        }
+    }
+
+    Ok(())
+}
+
+fn parse_collected<'a>(hand: &HandHistory, line: &'a str) -> Option<(&'a str, &'a str)> {
+    let player = seated_prefix(hand, line)?;
+    let amount = line[player.len()..].strip_prefix(" collected ")?;
+
+    Some((player, amount.split_whitespace().next()?))
+}
+
+fn split_action<'a>(hand: &HandHistory, line: &'a str) -> Option<(&'a str, &'a str)> {
+    let player = seated_prefix(hand, line)?;
+    let action = line[player.len()..].strip_prefix(": ")?;
+
+    Some((player, action))
+}
+
+/// The longest seated player name `line` starts with.
+fn seated_prefix<'a>(hand: &HandHistory, line: &'a str) -> Option<&'a str> {
+    hand.seats
+        .iter()
+        .filter(|seat| line.starts_with(&seat.name))
+        .max_by_key(|seat| seat.name.len())
+        .map(|seat| &line[..seat.name.len()])
+}
+
+fn parse_action(action: &str) -> io::Result<Option<Action>> {
+    let amount = |prefix: &str| -> io::Result<Amount> {
+        parse_amount(
+            action[prefix.len()..]
+                .split_whitespace()
+                .next()
+                .unwrap_or(""),
+        )
+    };
+
+    let action = if action.starts_with("posts the ante ") {
+        Action::Ante(amount("posts the ante ")?)
+    } else if action.starts_with("posts small & big blinds ") {
+        Action::Blinds(amount("posts small & big blinds ")?)
+    } else if action.starts_with("posts small blind ") {
+        Action::SmallBlind(amount("posts small blind ")?)
+    } else if action.starts_with("posts big blind ") {
+        Action::BigBlind(amount("posts big blind ")?)
+    } else if action.starts_with("folds") {
+        Action::Fold
+    } else if action.starts_with("checks") {
+        Action::Check
+    } else if action.starts_with("calls ") {
+        Action::Call(amount("calls ")?)
+    } else if action.starts_with("bets ") {
+        Action::Bet(amount("bets ")?)
+    } else if let Some(raise) = action.strip_prefix("raises ") {
+        let (by, to) = raise
+            .split_once(" to ")
+            .ok_or_else(|| invalid(format!("unexpected raise: {}", action)))?;
+        Action::Raise {
+            by: parse_amount(by)?,
+            to: parse_amount(to.split_whitespace().next().unwrap_or(""))?,
+        }
+    } else if action.starts_with("shows [") {
+        Action::Show(parse_bracketed(&action["shows ".len()..])?)
+    } else if action.starts_with("mucks hand") {
+        Action::Muck
+    } else {
+        return Ok(None);
+    };
+
+    Ok(Some(action))
+}
+
+fn add_shown(hand: &mut HandHistory, player: &str, cards: Vec<Card>) {
+    if !hand.shown.iter().any(|(name, _)| name == player) {
+        hand.shown.push((player.to_string(), cards));
+    }
+}
+
+fn street_of(section: Section) -> Street {
+    match section {
+        Section::Street(street) => street,
+        Section::Header => Street::Preflop,
+        Section::Showdown | Section::Summary => Street::River,
+    }
+}
+
+/// Parses `$1,234.56`, `€0.5` or `1500` into hundredths.
+fn parse_amount(raw: &str) -> io::Result<Amount> {
+    let digits = raw
+        .trim()
+        .trim_start_matches(['$', '€', '£'])
+        .replace(',', "");
+    let (whole, fraction) = digits.split_once('.').unwrap_or((&digits, ""));
+
+    let valid = |s: &str| s.chars().all(|c| c.is_ascii_digit());
+    if whole.is_empty() || !valid(whole) || !valid(fraction) || fraction.len() > 2 {
+        return Err(invalid(format!("invalid amount: {}", raw)));
+    }
+
+    let whole = whole
+        .parse::<Amount>()
+        .map_err(|_| invalid(format!("invalid amount: {}", raw)))?;
+    let fraction = format!("{:0<2}", fraction).parse::<Amount>().unwrap();
+
+    Ok(whole * 100 + fraction)
+}
+
+/// Cards between the first pair of square brackets, e.g. `[Ah Kd]`.
+fn parse_bracketed(raw: &str) -> io::Result<Vec<Card>> {
+    let inner = raw
+        .split_once('[')
+        .and_then(|(_, rest)| rest.split_once(']'))
+        .map(|(inner, _)| inner)
+        .ok_or_else(|| invalid(format!("missing cards: {}", raw)))?;
+
+    inner.split_whitespace().map(parse_card).collect()
+}
+
+fn parse_card(raw: &str) -> io::Result<Card> {
+    notation::card(raw).ok_or_else(|| invalid(format!("invalid card: {}", raw)))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::card::parse_cards;
+    use crate::engine::Limit;
+    use crate::engine::Street;
+    use crate::game::Game;
+    use crate::game::Kind;
+    use crate::history::pokerstars::parse;
+    use crate::history::pokerstars::parse_all;
+    use crate::history::Action;
+    use crate::history::PlayerAction;
+
+    const CASH: &str = include_str!("fixtures/pokerstars-cash.txt");
+
+    const TOURNAMENT: &str = include_str!("fixtures/pokerstars-tournament.txt");
+
+    #[test]
+    fn test_parse_cash() {
+        let hand = parse(CASH).unwrap();
+
+        assert_eq!(hand.id, "100001");
+        assert_eq!(hand.tournament, None);
+        assert_eq!(hand.kind, Kind::TexasHoldem);
+        assert_eq!(hand.limit, Limit::NoLimit);
+        assert_eq!((hand.small_blind, hand.big_blind), (1, 2));
+        assert_eq!(hand.button, 1);
+        assert_eq!(hand.seats.len(), 3);
+        assert_eq!(hand.seats[1].name, "bob");
+        assert_eq!(hand.seats[1].stack, 213);
+        assert_eq!(hand.board, parse_cards("2c5s9hJd3s"));
+        assert_eq!(
+            hand.shown,
+            vec![
+                ("bob".to_string(), parse_cards("KhKc")),
+                ("alice".to_string(), parse_cards("AhKd")),
+            ]
+        );
+        assert_eq!(hand.winners, vec![("bob".to_string(), 48)]);
+        assert_eq!((hand.total_pot, hand.rake), (50, 2));
+        assert_eq!(
+            hand.actions[2],
+            PlayerAction {
+                street: Street::Preflop,
+                player: "alice".to_string(),
+                action: Action::Raise { by: 4, to: 6 },
+            }
+        );
+        assert!(hand.folded("carol"));
+        assert!(!hand.folded("alice"));
+    }
+
+    #[test]
+    fn test_parse_tournament() {
+        let hand = parse(TOURNAMENT).unwrap();
+
+        assert_eq!(hand.tournament, Some("333".to_string()));
+        assert_eq!(hand.kind, Kind::OmahaHoldem);
+        assert_eq!(hand.limit, Limit::PotLimit);
+        assert_eq!((hand.small_blind, hand.big_blind), (1500, 3000));
+        assert_eq!(hand.seats[2].stack, 200000);
+        assert_eq!(hand.winners, vec![("finn".to_string(), 103500)]);
+        assert!(hand.actions.contains(&PlayerAction {
+            street: Street::Flop,
+            player: "finn".to_string(),
+            action: Action::Returned(500),
+        }));
+    }
+
+    #[test]
+    fn test_contributions() {
+        let hand = parse(CASH).unwrap();
+        assert_eq!(
+            hand.contributions(),
+            vec![
+                ("alice".to_string(), 24),
+                ("bob".to_string(), 24),
+                ("carol".to_string(), 2),
+            ]
+        );
+
+        let hand = parse(TOURNAMENT).unwrap();
+        let total = hand.contributions().iter().map(|(_, c)| c).sum::<u64>();
+        assert_eq!(total, hand.total_pot);
+    }
+
+    #[test]
+    fn test_game() {
+        match parse(CASH).unwrap().game().unwrap() {
+            Game::TexasHoldem(board, hands) => {
+                assert_eq!(board.cards(), parse_cards("2c5s9hJd3s").as_slice());
+                assert_eq!(hands[0].name, Some("bob".to_string()));
+                assert_eq!(hands[0].hand.cards(), parse_cards("KhKc").as_slice());
+                assert_eq!(hands[1].name, Some("alice".to_string()));
+            }
+            _ => panic!("unexpected game"),
+        }
+
+        let game = parse(TOURNAMENT).unwrap().game().unwrap();
+        assert_eq!(game.kind(), Kind::OmahaHoldem);
+    }
+
+    #[test]
+    fn test_parse_all() {
+        let text = format!(
+            "{}\n\n\n{}\n\nPokerStars Hand #3: Razz Limit (1/2)\n",
+            CASH, TOURNAMENT
+        );
+        let hands = parse_all(&text);
+
+        assert_eq!(hands.len(), 3);
+        assert_eq!(hands[0].as_ref().unwrap().id, "100001");
+        assert_eq!(hands[1].as_ref().unwrap().id, "100002");
+        assert!(hands[2].is_err());
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert!(parse("").is_err());
+        assert!(parse(&CASH.replace("[Kh Kc]", "[Kh Kx]")).is_err());
+        assert!(parse(&CASH.replace("$0.08", "$0.0.8")).is_err());
+        assert!(parse(&CASH.replace("Hold'em", "Omaha Hi/Lo")).is_err());
+    }
+}
@@ -0,0 +1,281 @@
+//! One JSON object per line in, one per line out:
+//!
+//! `{"game": "texas-holdem", "board": ["4c", ...], "hands": [{"name": "alice", "cards": ["Ad", "4s"]}, ...]}`
+//!
+//! Hands may also be plain arrays of cards when they have no name.
+
+use std::io;
+
+use ::serde::Serialize;
+
+use crate::card::Card;
+use crate::diagnostic;
+use crate::game::three_card_poker::Outcome;
+use crate::game::three_card_poker::Paytable;
+use crate::game::Game;
+use crate::game::HandOf2;
+use crate::game::HandOf3;
+use crate::game::HandOf4;
+use crate::game::HandOf5;
+use crate::game::Named;
+use crate::game::Ranking;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RankedHand {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    pub cards: Vec<Card>,
+    pub combination: Ranking,
+    pub category: &'static str,
+    pub best: Vec<Card>,
+    /// 1 for the best hand; tied hands share a position.
+    pub position: usize,
+    /// Three card poker only, against the dealer with the default paytable.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub outcome: Option<Outcome>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Output {
+    pub hands: Vec<RankedHand>,
+}
+
+pub fn process(line: &str) -> io::Result<String> {
+    Ok(::serde_json::to_string(&rank(parse(line)?))?)
+}
+
+/// Errors carry a `Diagnostic` at the line and column where serde_json
+/// stopped, just past the offending value, or with the whole line as the
+/// token when the game as a whole is at fault. Cards dealt twice are
+/// rejected as well.
+pub fn parse(line: &str) -> io::Result<Game> {
+    let game = ::serde_json::from_str::<Game>(line).map_err(|error| {
+        let message = error.to_string();
+        let message = message
+            .rsplit_once(" at line ")
+            .map_or(message.as_str(), |(message, _)| message);
+
+        // Errors found once the whole game is read have no position.
+        if error.line() == 0 {
+            return diagnostic::error(1, line, message);
+        }
+
+        let at = offset(line, error.line(), error.column());
+        let end = line[at..].chars().next().map_or(at, |c| at + c.len_utf8());
+
+        diagnostic::error(line[..at].chars().count() + 1, &line[at..end], message)
+    })?;
+
+    let (dealt, hands) = match &game {
+        Game::TexasHoldem(board, hands) => (board.0.as_slice(), cards(hands, HandOf2::cards)),
+        Game::OmahaHoldem(board, hands) => (board.0.as_slice(), cards(hands, HandOf4::cards)),
+        Game::FiveCardDraw(hands) => (&[][..], cards(hands, HandOf5::cards)),
+        Game::ThreeCardPoker(dealer, hands) => (dealer.cards(), cards(hands, HandOf3::cards)),
+    };
+
+    let mut seen = vec![];
+    for card in dealt.iter().chain(hands.into_iter().flatten()) {
+        if seen.contains(card) {
+            return Err(diagnostic::error(
+                1,
+                line,
+                format!("card {} is dealt twice", card),
+            ));
+        }
+        seen.push(*card);
+    }
+
+    Ok(game)
+}
+
+fn cards<H>(hands: &[Named<H>], cards: fn(&H) -> &[Card]) -> Vec<&[Card]> {
+    hands.iter().map(|named| cards(&named.hand)).collect()
+}
+
+/// The byte offset of serde's 1-based `line` and `column`, themselves in
+/// bytes, at the start of a character.
+fn offset(text: &str, line: usize, column: usize) -> usize {
+    let start = text
+        .split('\n')
+        .take(line.saturating_sub(1))
+        .map(|line| line.len() + 1)
+        .sum::<usize>();
+    let mut at = (start + column.saturating_sub(1)).min(text.len());
+    while !text.is_char_boundary(at) {
+        at -= 1;
+    }
+
+    at
+}
+
+pub fn rank(game: Game) -> Output {
+    let best = Game::best_cards(&game);
+    let outcomes = game.settle_three_card_poker(&Paytable::default());
+    let ranked = Game::rank_hands(game);
+
+    let hands = ranked
+        .iter()
+        .zip(best)
+        .enumerate()
+        .map(|(i, ((named, combination), best))| RankedHand {
+            name: named.name.clone(),
+            cards: named.hand.clone(),
+            combination: *combination,
+            category: combination.category(),
+            best,
+            position: 1 + ranked
+                .iter()
+                .map(|(_, other)| other)
+                .filter(|other| *other > combination)
+                .fold(Vec::<&Ranking>::new(), |mut better, other| {
+                    if !better.contains(&other) {
+                        better.push(other);
+                    }
+                    better
+                })
+                .len(),
+            outcome: outcomes.get(i).copied(),
+        })
+        .collect();
+
+    Output { hands }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::diagnostic;
+    use crate::json::parse;
+    use crate::json::process;
+
+    #[test]
+    fn test_process() {
+        let output = process(
+            r#"{"game":"texas-holdem","board":["4c","Ks","4h","8s","7s"],"hands":[{"name":"alice","cards":["Ad","4s"]},["Ac","4d"],{"name":"carol","cards":["As","9s"]}]}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            output,
+            concat!(
+                r#"{"hands":["#,
+                r#"{"name":"alice","cards":["Ad","4s"],"combination":{"category":"three_of_a_kind","rank":"4","extra":["A","K"]},"category":"three_of_a_kind","best":["4c","Ks","4h","Ad","4s"],"position":2},"#,
+                r#"{"cards":["Ac","4d"],"combination":{"category":"three_of_a_kind","rank":"4","extra":["A","K"]},"category":"three_of_a_kind","best":["4c","Ks","4h","Ac","4d"],"position":2},"#,
+                r#"{"name":"carol","cards":["As","9s"],"combination":{"category":"flush","rank":"A","extra":["K","9","8","7"]},"category":"flush","best":["Ks","8s","7s","As","9s"],"position":1}"#,
+                r#"]}"#
+            )
+        );
+    }
+
+    #[test]
+    fn test_process_five_card_draw() {
+        let output =
+            process(r#"{"game":"five-card-draw","hands":[["7h","4s","4h","8c","9h"]]}"#).unwrap();
+
+        assert!(output.contains(r#""category":"pair""#));
+        assert!(output.contains(r#""position":1"#));
+    }
+
+    #[test]
+    fn test_process_three_card_poker() {
+        let output = process(
+            r#"{"game":"three-card-poker","dealer":["Qh","7d","2c"],"hands":[{"name":"bob","cards":["5c","6d","7s"]}]}"#,
+        )
+        .unwrap();
+
+        assert!(output.contains(r#""outcome":{"plays":true,"ante":2,"play":1,"pair_plus":6}"#));
+        assert!(
+            !process(r#"{"game":"five-card-draw","hands":[["7h","4s","4h","8c","9h"]]}"#)
+                .unwrap()
+                .contains("outcome")
+        );
+    }
+
+    #[test]
+    fn test_parse_diagnostic() {
+        let diagnostic = |line: &str| {
+            let error = parse(line).unwrap_err();
+            let diagnostic = diagnostic::of(&error).unwrap().clone();
+
+            (diagnostic.column, diagnostic.token, diagnostic.message)
+        };
+
+        assert_eq!(
+            diagnostic(r#"{"game":"stud"}"#),
+            (15, "}".to_string(), "unknown game: stud".to_string())
+        );
+        assert_eq!(
+            diagnostic(r#"{"game":"texas-holdem","board":["4c","Ks"],"hands":[]}"#),
+            (42, "]".to_string(), "unexpected board size: 2".to_string())
+        );
+        assert_eq!(
+            diagnostic(r#"{"game":"texas-holdem","board":["4c","Ks","4h"],"hands":[["Ad","Zz"]]}"#),
+            (68, "]".to_string(), "unknown card value: Zz".to_string())
+        );
+        let line = r#"{"game":"texas-holdem","board":["4c","Ks","4h"],"hands":[["Ad","Kd"],["Ad","4d","2c"]]}"#;
+        assert_eq!(
+            diagnostic(line),
+            (1, line.to_string(), "expected 2 cards, got 3".to_string())
+        );
+        assert_eq!(
+            diagnostic("texas-holdem 4c"),
+            (2, "e".to_string(), "expected ident".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_duplicated_card() {
+        let line = r#"{"game":"texas-holdem","board":["4c","Ks","4h"],"hands":[["Ad","Kd"],{"name":"bob","cards":["Ks","4d"]}]}"#;
+        let error = parse(line).unwrap_err();
+        let diagnostic = diagnostic::of(&error).unwrap();
+
+        assert_eq!(diagnostic.message, "card Ks is dealt twice");
+        assert_eq!(diagnostic.column, 1);
+        assert_eq!(diagnostic.token, line);
+
+        let error = parse(
+            r#"{"game":"three-card-poker","dealer":["Qh","7d","2c"],"hands":[["Qh","6d","7s"]]}"#,
+        )
+        .unwrap_err();
+        assert_eq!(error.to_string(), "card Qh is dealt twice");
+
+        // A key given twice is not a card dealt twice.
+        let error = parse(
+            r#"{"game":"texas-holdem","board":["4c","Ks","4h"],"board":["4c","Ks","4h"],"hands":[["Ad","Kd"]]}"#,
+        )
+        .unwrap_err();
+        assert_eq!(error.to_string(), "duplicate field `board`");
+    }
+
+    #[test]
+    fn test_parse_escaped_quote() {
+        let line = r#"{"game":"texas-holdem","board":["4c","Ks","4h"],"hands":[{"name":"a\"],[\"b","cards":["Ad","Zz"]}]}"#;
+        let error = parse(line).unwrap_err();
+        let diagnostic = diagnostic::of(&error).unwrap();
+
+        assert_eq!(diagnostic.message, "unknown card value: Zz");
+        assert_eq!(diagnostic.column, 96);
+        assert_eq!(diagnostic.token, "]");
+
+        let line = r#"{"game":"five-card-draw","hands":[{"name":"\u00e9\"","cards":["A♥","Kd","Qd","Jd","Z♥"]}]}"#;
+        let error = parse(line).unwrap_err();
+        let diagnostic = diagnostic::of(&error).unwrap();
+
+        assert_eq!(diagnostic.message, "unknown card value: Z♥");
+        // In characters, though serde_json counts bytes.
+        assert_eq!(diagnostic.column, 87);
+    }
+
+    #[test]
+    fn test_process_invalid() {
+        assert!(process("texas-holdem 4cKs4h8s7s Ad4s").is_err());
+        assert!(process(r#"{"game":"texas-holdem","hands":[["Ad","4s"]]}"#).is_err());
+        assert!(process(
+            r#"{"game":"omaha-holdem","board":["4c","Ks","4h"],"hands":[["Ad","4s"]]}"#
+        )
+        .is_err());
+        assert!(process(
+            r#"{"game":"texas-holdem","board":["4c","Ks","4h"],"hands":[["Ad","Zz"]]}"#
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,19 @@
+pub mod batch;
+pub mod card;
+pub mod combination;
+pub mod deck;
+pub mod diagnostic;
+pub mod engine;
+pub mod game;
+pub mod history;
+#[cfg(feature = "json")]
+pub mod json;
+pub mod listen;
+pub mod payout;
+pub mod preflop;
+pub mod repl;
+#[cfg(feature = "json")]
+pub mod serve;
+pub mod text;
+pub mod three_card;
+pub mod video_poker;
@@ -0,0 +1,276 @@
+//! The CLI line format over persistent TCP connections. Each request line
+//! may start with an ID, echoed back so clients can pipeline:
+//!
+//! `7 texas-holdem 4cKs4h8s7s Ad4s As9s` gets `7 ok Ad4s As9s`,
+//! `8 texas-holdem 4cKs` gets `8 error unexpected board size: 2`.
+//!
+//! Lines without an ID get `ok ...` or `error ...`.
+
+use std::io;
+use std::io::BufRead;
+use std::io::BufReader;
+use std::io::Read;
+use std::io::Write;
+use std::net::SocketAddr;
+use std::net::TcpListener;
+use std::net::TcpStream;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use crate::card::notation::Style;
+
+/// How often idle listeners and connections check for shutdown.
+const POLL: Duration = Duration::from_millis(100);
+/// Longest request line accepted, newline included.
+const MAX_LINE: usize = 8 * 1024;
+
+const GAMES: [&str; 4] = [
+    "texas-holdem",
+    "omaha-holdem",
+    "five-card-draw",
+    "three-card-poker",
+];
+
+pub struct Server {
+    listener: TcpListener,
+    shutdown: Arc<AtomicBool>,
+}
+
+impl Server {
+    pub fn bind(addr: &str) -> io::Result<Self> {
+        let listener = TcpListener::bind(addr)?;
+        listener.set_nonblocking(true)?;
+
+        Ok(Self {
+            listener,
+            shutdown: Arc::new(AtomicBool::new(false)),
+        })
+    }
+
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    /// Setting the flag stops the server: no new connections are accepted,
+    /// open ones finish the line in progress and are closed.
+    pub fn shutdown(&self) -> Arc<AtomicBool> {
+        self.shutdown.clone()
+    }
+
+    /// Serves connections, one thread each, until shut down and every
+    /// connection is closed.
+    pub fn run(self) -> io::Result<()> {
+        let mut connections = vec![];
+
+        while !self.shutdown.load(Ordering::SeqCst) {
+            match self.listener.accept() {
+                Ok((stream, _)) => {
+                    let shutdown = self.shutdown.clone();
+
+                    connections.push(thread::spawn(move || {
+                        if let Err(error) = connection(stream, &shutdown) {
+                            eprintln!("connection failed: {}", error);
+                        }
+                    }));
+                }
+                Err(error) if error.kind() == io::ErrorKind::WouldBlock => thread::sleep(POLL),
+                Err(error) => return Err(error),
+            }
+
+            connections.retain(|connection| !connection.is_finished());
+        }
+
+        for connection in connections {
+            let _ = connection.join();
+        }
+
+        Ok(())
+    }
+}
+
+/// Serves one connection until the client closes it, the server shuts down
+/// or a line is too long, which is answered before closing.
+fn connection(stream: TcpStream, shutdown: &AtomicBool) -> io::Result<()> {
+    stream.set_nonblocking(false)?;
+    stream.set_read_timeout(Some(POLL))?;
+
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let mut writer = stream;
+    let mut line = vec![];
+
+    loop {
+        let limit = (MAX_LINE - line.len()) as u64;
+
+        match (&mut reader).take(limit).read_until(b'\n', &mut line) {
+            Ok(_) if line.ends_with(b"\n") => {
+                let response = respond(&String::from_utf8_lossy(&line), Style::Ascii);
+                writeln!(writer, "{}", response)?;
+                line.clear();
+
+                if shutdown.load(Ordering::SeqCst) {
+                    return Ok(());
+                }
+            }
+            Ok(_) if line.len() == MAX_LINE => {
+                writeln!(writer, "error line too long")?;
+
+                return Ok(());
+            }
+            // The client closed the connection, maybe in the middle of a line.
+            Ok(_) => return Ok(()),
+            Err(error)
+                if matches!(
+                    error.kind(),
+                    io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut
+                ) =>
+            {
+                if shutdown.load(Ordering::SeqCst) {
+                    return Ok(());
+                }
+            }
+            Err(error) => return Err(error),
+        }
+    }
+}
+
+/// The response line to one request line, never panicking on bad input.
+pub fn respond(line: &str, style: Style) -> String {
+    let line = line.trim();
+
+    let (id, request) = match line.split_whitespace().next() {
+        Some(first) if !GAMES.contains(&first) => (Some(first), line[first.len()..].trim()),
+        _ => (None, line),
+    };
+
+    let result = match crate::text::process(request, style) {
+        Ok(result) => format!("ok {}", result),
+        Err(error) => format!("error {}", error),
+    };
+
+    match id {
+        Some(id) => format!("{} {}", id, result),
+        None => result,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::BufRead;
+    use std::io::BufReader;
+    use std::io::Write;
+    use std::net::TcpStream;
+    use std::sync::atomic::Ordering;
+    use std::thread;
+
+    use crate::card::notation::Style;
+    use crate::listen::respond;
+    use crate::listen::Server;
+    use crate::listen::MAX_LINE;
+
+    #[test]
+    fn test_respond() {
+        assert_eq!(
+            respond("7 texas-holdem 4cKs4h8s7s Ad4s As9s\n", Style::Ascii),
+            "7 ok Ad4s As9s"
+        );
+        assert_eq!(
+            respond("texas-holdem 4cKs4h8s7s Ad4s As9s", Style::Ascii),
+            "ok Ad4s As9s"
+        );
+        assert_eq!(
+            respond("req-8 texas-holdem 4cKs", Style::Ascii),
+            "req-8 error unexpected board size: 2"
+        );
+        assert_eq!(respond("9", Style::Ascii), "9 error empty line");
+        assert_eq!(respond("", Style::Ascii), "error empty line");
+    }
+
+    #[test]
+    fn test_server() {
+        let server = Server::bind("127.0.0.1:0").unwrap();
+        let addr = server.local_addr().unwrap();
+        let shutdown = server.shutdown();
+        let running = thread::spawn(move || server.run());
+
+        let mut clients = (0..3)
+            .map(|_| TcpStream::connect(addr).unwrap())
+            .collect::<Vec<_>>();
+
+        for (i, client) in clients.iter_mut().enumerate() {
+            write!(
+                client,
+                "{}a texas-holdem 4cKs4h8s7s Ad4s As9s\n{}b stud\n",
+                i, i
+            )
+            .unwrap();
+        }
+
+        for (i, client) in clients.iter().enumerate() {
+            let mut lines = BufReader::new(client).lines();
+
+            assert_eq!(
+                lines.next().unwrap().unwrap(),
+                format!("{}a ok Ad4s As9s", i)
+            );
+            assert_eq!(
+                lines.next().unwrap().unwrap(),
+                format!("{}b error unknown game: stud", i)
+            );
+        }
+
+        shutdown.store(true, Ordering::SeqCst);
+        running.join().unwrap().unwrap();
+
+        // Connections are closed once the server has stopped.
+        let mut lines = BufReader::new(&clients[0]).lines();
+        assert!(lines.next().is_none());
+    }
+
+    #[test]
+    fn test_line_too_long() {
+        let server = Server::bind("127.0.0.1:0").unwrap();
+        let addr = server.local_addr().unwrap();
+        let shutdown = server.shutdown();
+        let running = thread::spawn(move || server.run());
+
+        let mut client = TcpStream::connect(addr).unwrap();
+        writeln!(client, "1 texas-holdem 4cKs4h8s7s Ad4s As9s").unwrap();
+        client.write_all(&vec![b'a'; MAX_LINE]).unwrap();
+
+        let mut lines = BufReader::new(&client).lines();
+        assert_eq!(lines.next().unwrap().unwrap(), "1 ok Ad4s As9s");
+        assert_eq!(lines.next().unwrap().unwrap(), "error line too long");
+        assert!(lines.next().is_none());
+
+        shutdown.store(true, Ordering::SeqCst);
+        running.join().unwrap().unwrap();
+    }
+
+    #[test]
+    fn test_shutdown_between_lines() {
+        let server = Server::bind("127.0.0.1:0").unwrap();
+        let addr = server.local_addr().unwrap();
+        let shutdown = server.shutdown();
+        let running = thread::spawn(move || server.run());
+
+        let mut client = TcpStream::connect(addr).unwrap();
+        let mut lines = BufReader::new(client.try_clone().unwrap()).lines();
+
+        writeln!(client, "1 texas-holdem 4cKs4h8s7s Ad4s As9s").unwrap();
+        assert_eq!(lines.next().unwrap().unwrap(), "1 ok Ad4s As9s");
+
+        // Requests keep coming, yet the connection is closed after the
+        // next response.
+        shutdown.store(true, Ordering::SeqCst);
+        let requests = (2..5)
+            .map(|i| format!("{} texas-holdem 4cKs4h8s7s Ad4s As9s\n", i))
+            .collect::<String>();
+        client.write_all(requests.as_bytes()).unwrap();
+
+        running.join().unwrap().unwrap();
+        assert!(lines.map_while(Result::ok).count() <= 1);
+    }
+}
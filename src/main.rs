@@ -1,51 +1,525 @@
+use std::convert::TryInto;
+use std::fs::File;
+use std::io;
 use std::io::BufRead;
+use std::io::BufReader;
+use std::io::BufWriter;
+use std::io::Write;
+use std::process;
 use std::str::FromStr;
 
 use ::itertools::Itertools;
+use ::rand::SeedableRng;
+use ::rand_chacha::ChaCha8Rng;
+use ::signal_hook::consts::SIGINT;
+use ::signal_hook::consts::SIGTERM;
 
-mod card;
-mod combination;
-mod game;
+use poker::batch;
+use poker::card::notation;
+use poker::card::notation::Style;
+use poker::card::Card;
+use poker::combination::Combination;
+use poker::deck::Deck;
+use poker::diagnostic;
+use poker::game::equity::Spot;
+use poker::game::Kind;
+use poker::game::Variant;
 
-use card::Card;
-use game::Game;
+const USAGE: &str = "\
+usage: poker [COMMAND] [FLAGS]
+
+commands reading one request per line:
+  rank       rank the hands of each game (the default)
+  eval       name the combination of five cards
+  equity     equity of hands all-in, e.g. AhKh vs QsQd board 2h7h9c
+  validate   check games without ranking them
+flags: --input FILE --output FILE --format text|json
+       --cards ascii|unicode|four-color --jobs N
+       --strict (stop at the first invalid line)
+       --lenient (report invalid lines and go on, the default)
+       equity only: --samples N --seed N
+blank lines and lines starting with # are skipped
+
+other commands:
+  generate   random games: --game KIND --players N --count N --seed N
+             --output FILE --format text|json
+  serve      HTTP/JSON on localhost: --port N
+  listen     line protocol over TCP on localhost: --port N
+  repl       interactive session
+
+exit status: 0 on success, 1 when some input was rejected,
+2 on usage errors, 3 on I/O errors";
+
+/// Some input lines were rejected.
+const EXIT_INVALID: i32 = 1;
+const EXIT_USAGE: i32 = 2;
+const EXIT_IO: i32 = 3;
+
+/// Runouts drawn per line by `equity` unless `--samples` says otherwise.
+const SAMPLES: usize = 10_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Command {
+    Rank,
+    Eval,
+    Equity,
+    Validate,
+    Generate,
+    #[cfg(feature = "json")]
+    Serve,
+    Listen,
+    Repl,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Text,
+    #[cfg(feature = "json")]
+    Json,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Options {
+    input: Option<String>,
+    output: Option<String>,
+    format: Format,
+    style: Style,
+    jobs: usize,
+    samples: usize,
+    seed: Option<u64>,
+    game: Kind,
+    players: usize,
+    count: usize,
+    port: Option<u16>,
+    strict: bool,
+}
 
 fn main() {
-    let stdin = std::io::stdin();
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
 
-    for line in stdin.lock().lines() {
-        process(&line.unwrap())
+    if matches!(
+        args.first().map(String::as_str),
+        Some("help" | "--help" | "-h")
+    ) {
+        println!("{}", USAGE);
+        return;
+    }
+
+    let (command, options) = match parse(&args) {
+        Ok(parsed) => parsed,
+        Err(error) => {
+            eprintln!("{}\n\n{}", error, USAGE);
+            process::exit(EXIT_USAGE);
+        }
+    };
+
+    let result = match command {
+        Command::Rank | Command::Eval | Command::Equity | Command::Validate => {
+            lines(command, &options)
+        }
+        Command::Generate => generate(&options),
+        #[cfg(feature = "json")]
+        Command::Serve => {
+            poker::serve::run(&format!("127.0.0.1:{}", options.port.unwrap_or(8080))).map(|_| 0)
+        }
+        Command::Listen => listen(&options),
+        Command::Repl => {
+            let stdin = io::stdin();
+            poker::repl::Repl::new()
+                .run(stdin.lock(), io::stdout())
+                .map(|_| 0)
+        }
+    };
+
+    match result {
+        Ok(0) => {}
+        Ok(code) => process::exit(code),
+        Err(error) => {
+            eprintln!("poker: {}", error);
+            process::exit(EXIT_IO);
+        }
     }
 }
 
-fn process(line: &str) {
-    let game = Game::from_str(line).unwrap();
+/// The command and its flags; without a command the flags are those of `rank`.
+fn parse(args: &[String]) -> Result<(Command, Options), String> {
+    let (command, flags) = match args.first().map(String::as_str) {
+        None => (Command::Rank, args),
+        Some(flag) if flag.starts_with("--") => (Command::Rank, args),
+        Some(name) => {
+            let command = match name {
+                "rank" => Command::Rank,
+                "eval" => Command::Eval,
+                "equity" => Command::Equity,
+                "validate" => Command::Validate,
+                "generate" => Command::Generate,
+                #[cfg(feature = "json")]
+                "serve" => Command::Serve,
+                "listen" => Command::Listen,
+                "repl" => Command::Repl,
+                _ => return Err(format!("unknown command: {}", name)),
+            };
+            (command, &args[1..])
+        }
+    };
+
+    let allowed: &[&str] = match command {
+        Command::Rank | Command::Eval | Command::Validate => &[
+            "--input",
+            "--output",
+            "--format",
+            "--cards",
+            "--jobs",
+            "--strict",
+            "--lenient",
+        ],
+        Command::Equity => &[
+            "--input",
+            "--output",
+            "--format",
+            "--cards",
+            "--jobs",
+            "--strict",
+            "--lenient",
+            "--samples",
+            "--seed",
+        ],
+        Command::Generate => &[
+            "--output",
+            "--format",
+            "--game",
+            "--players",
+            "--count",
+            "--seed",
+        ],
+        #[cfg(feature = "json")]
+        Command::Serve => &["--port"],
+        Command::Listen => &["--port"],
+        Command::Repl => &[],
+    };
 
-    let ranked_hands = Game::rank_hands(game);
-    let grouped_hands = Game::group_hands(ranked_hands);
-    let sorted_hands = Game::sort_hands(grouped_hands);
+    let mut options = Options {
+        input: None,
+        output: None,
+        format: Format::Text,
+        style: Style::Ascii,
+        jobs: 1,
+        samples: SAMPLES,
+        seed: None,
+        game: Kind::TexasHoldem,
+        players: 2,
+        count: 1,
+        port: None,
+        strict: false,
+    };
 
-    output(sorted_hands);
+    let mut flags = flags.iter().map(String::as_str);
+
+    while let Some(flag) = flags.next() {
+        if !allowed.contains(&flag) {
+            return Err(format!("unknown flag: {}", flag));
+        }
+
+        match flag {
+            "--strict" => {
+                options.strict = true;
+                continue;
+            }
+            "--lenient" => {
+                options.strict = false;
+                continue;
+            }
+            _ => {}
+        }
+
+        let value = flags
+            .next()
+            .ok_or_else(|| format!("missing value for {}", flag))?;
+
+        match flag {
+            "--input" => options.input = Some(value.to_string()),
+            "--output" => options.output = Some(value.to_string()),
+            "--format" => {
+                options.format = match value {
+                    "text" => Format::Text,
+                    #[cfg(feature = "json")]
+                    "json" => Format::Json,
+                    _ => return Err(format!("unknown format: {}", value)),
+                }
+            }
+            "--cards" => {
+                options.style = Style::from_str(value).map_err(|error| error.to_string())?
+            }
+            "--game" => options.game = Kind::from_str(value).map_err(|error| error.to_string())?,
+            "--jobs" => options.jobs = positive(flag, value)?,
+            "--samples" => options.samples = positive(flag, value)?,
+            "--players" => options.players = positive(flag, value)?,
+            "--count" => options.count = positive(flag, value)?,
+            "--seed" => options.seed = Some(number(flag, value)?),
+            "--port" => options.port = Some(number(flag, value)?),
+            _ => unreachable!(),
+        }
+    }
+
+    Ok((command, options))
+}
+
+fn number<T: FromStr>(flag: &str, value: &str) -> Result<T, String> {
+    value
+        .parse()
+        .map_err(|_| format!("invalid value for {}: {}", flag, value))
+}
+
+fn positive(flag: &str, value: &str) -> Result<usize, String> {
+    match number(flag, value)? {
+        0 => Err(format!("invalid value for {}: {}", flag, value)),
+        n => Ok(n),
+    }
+}
+
+fn input(options: &Options) -> io::Result<Box<dyn BufRead>> {
+    Ok(match &options.input {
+        Some(path) => Box::new(BufReader::new(File::open(path)?)),
+        None => Box::new(BufReader::new(io::stdin())),
+    })
+}
+
+fn output(options: &Options) -> io::Result<Box<dyn Write>> {
+    Ok(match &options.output {
+        Some(path) => Box::new(BufWriter::new(File::create(path)?)),
+        None => Box::new(BufWriter::new(io::stdout())),
+    })
 }
 
-fn output(hands: Vec<Vec<Vec<Card>>>) {
-    let line = hands
-        .into_iter()
-        .map(|group| {
-            group
-                .into_iter()
-                .map(|hand| {
-                    hand.into_iter()
-                        .map(|card| card.to_string())
-                        .format("")
-                        .to_string()
-                })
-                .sorted()
-                .format("=")
-                .to_string()
+/// Runs a line command over the input, writing results in input order and
+/// rejected lines to stderr with their line and column. Leniently a marker
+/// takes the place of the result; strictly the first rejected line ends
+/// the run.
+fn lines(command: Command, options: &Options) -> io::Result<i32> {
+    let source = options.input.as_deref().unwrap_or("<stdin>");
+    let mut lines = input(options)?.lines();
+    let mut output = output(options)?;
+
+    // Without workers lines are answered one by one, which keeps pipes interactive.
+    let size = match options.jobs {
+        1 => 1,
+        jobs => jobs * batch::CHUNK,
+    };
+
+    let mut number = 0;
+    let mut checked = 0;
+    let mut rejected = 0;
+
+    loop {
+        let mut chunk = vec![];
+        let mut numbers = vec![];
+
+        while chunk.len() < size {
+            let line = match lines.next() {
+                Some(line) => line?,
+                None => break,
+            };
+            number += 1;
+
+            if !skipped(&line) {
+                chunk.push(line);
+                numbers.push(number);
+            }
+        }
+
+        if chunk.is_empty() {
+            break;
+        }
+
+        let results = batch::process(&chunk, options.jobs, |line| request(command, line, options));
+
+        for ((result, line), number) in results.into_iter().zip(&chunk).zip(numbers) {
+            checked += 1;
+
+            let error = match result {
+                Ok(result) if result.is_empty() => continue,
+                Ok(result) => {
+                    writeln!(output, "{}", result)?;
+                    continue;
+                }
+                Err(error) => error,
+            };
+
+            let (column, token) = match diagnostic::of(&error) {
+                Some(diagnostic) => (diagnostic.column, diagnostic.token.as_str()),
+                None => (1, line.trim()),
+            };
+            rejected += 1;
+
+            eprintln!(
+                "{}:{}:{}: {} near `{}`",
+                source, number, column, error, token
+            );
+
+            if options.strict {
+                output.flush()?;
+                return Ok(EXIT_INVALID);
+            }
+            if command != Command::Validate {
+                writeln!(output, "{}", marker(&error, number, column, options.format))?;
+            }
+        }
+
+        output.flush()?;
+    }
+
+    if command == Command::Validate {
+        eprintln!("{} lines checked, {} invalid", checked, rejected);
+    }
+
+    Ok(if rejected > 0 { EXIT_INVALID } else { 0 })
+}
+
+/// Blank lines and `#` comments are not requests.
+fn skipped(line: &str) -> bool {
+    let line = line.trim();
+
+    line.is_empty() || line.starts_with('#')
+}
+
+/// Stands in the output for a rejected line, so results stay aligned with
+/// requests.
+fn marker(error: &io::Error, number: usize, column: usize, format: Format) -> String {
+    match format {
+        Format::Text => format!("# error at {}:{}: {}", number, column, error),
+        #[cfg(feature = "json")]
+        Format::Json => ::serde_json::json!({
+            "error": error.to_string(),
+            "line": number,
+            "column": column,
+        })
+        .to_string(),
+    }
+}
+
+fn request(command: Command, line: &str, options: &Options) -> io::Result<String> {
+    match (command, options.format) {
+        (Command::Rank, Format::Text) => poker::text::process(line, options.style),
+        #[cfg(feature = "json")]
+        (Command::Rank, Format::Json) => poker::json::process(line),
+        (Command::Validate, Format::Text) => poker::text::parse(line).map(|_| String::new()),
+        #[cfg(feature = "json")]
+        (Command::Validate, Format::Json) => poker::json::parse(line).map(|_| String::new()),
+        (Command::Eval, format) => eval(line, format, options.style),
+        (Command::Equity, format) => equity(line, format, options),
+        _ => unreachable!(),
+    }
+}
+
+fn eval(line: &str, format: Format, style: Style) -> io::Result<String> {
+    let cards =
+        notation::cards(line).ok_or_else(|| invalid(format!("invalid cards: {}", line.trim())))?;
+    if let Some((_, card)) = cards
+        .iter()
+        .enumerate()
+        .find(|(i, card)| cards[..*i].contains(card))
+    {
+        return Err(invalid(format!("card {} is dealt twice", card)));
+    }
+
+    let cards: [Card; 5] = cards
+        .try_into()
+        .map_err(|cards: Vec<Card>| invalid(format!("expected 5 cards, got {}", cards.len())))?;
+    let combination = Combination::from_variant(Variant(cards));
+
+    Ok(match format {
+        Format::Text => format!(
+            "{} {}",
+            cards.iter().map(|card| card.styled(style)).join(""),
+            combination
+        ),
+        #[cfg(feature = "json")]
+        Format::Json => ::serde_json::json!({
+            "cards": cards,
+            "combination": combination,
+            "category": combination.category(),
+        })
+        .to_string(),
+    })
+}
+
+fn equity(line: &str, format: Format, options: &Options) -> io::Result<String> {
+    let spot = Spot::parse(line.trim())?;
+    // Seeded per line so results do not depend on the number of jobs.
+    let mut rng = match options.seed {
+        Some(seed) => ChaCha8Rng::seed_from_u64(seed),
+        None => ChaCha8Rng::from_entropy(),
+    };
+    let equity = spot.equity(options.samples, &mut rng);
+
+    Ok(match format {
+        Format::Text => spot
+            .hands
+            .iter()
+            .zip(&equity)
+            .map(|(hand, equity)| {
+                let hand = hand.iter().map(|card| card.styled(options.style)).join("");
+                format!("{} {:.2}%", hand, equity * 100.0)
+            })
+            .join(" "),
+        #[cfg(feature = "json")]
+        Format::Json => ::serde_json::json!({
+            "hands": spot.hands,
+            "board": spot.board,
+            "equity": equity,
         })
-        .format(" ")
-        .to_string();
+        .to_string(),
+    })
+}
+
+fn generate(options: &Options) -> io::Result<i32> {
+    let (board, hole) = match options.game {
+        Kind::TexasHoldem => (5, 2),
+        Kind::OmahaHoldem => (5, 4),
+        Kind::FiveCardDraw => (0, 5),
+        Kind::ThreeCardPoker => (3, 3),
+    };
+    if board + options.players * hole > 52 {
+        eprintln!("poker: not enough cards for {} players", options.players);
+        return Ok(EXIT_USAGE);
+    }
+
+    let mut output = output(options)?;
+
+    for i in 0..options.count as u64 {
+        let mut deck = match options.seed {
+            Some(seed) => Deck::seeded(seed.wrapping_add(i)),
+            None => {
+                let mut deck = Deck::new();
+                deck.shuffle(&mut ChaCha8Rng::from_entropy());
+                deck
+            }
+        };
+        let game = deck.deal_game(options.game, options.players);
+
+        match options.format {
+            Format::Text => writeln!(output, "{}", game)?,
+            #[cfg(feature = "json")]
+            Format::Json => writeln!(output, "{}", ::serde_json::to_string(&game)?)?,
+        }
+    }
+
+    output.flush()?;
+
+    Ok(0)
+}
+
+/// Serves the line format over TCP until SIGINT or SIGTERM.
+fn listen(options: &Options) -> io::Result<i32> {
+    let addr = format!("127.0.0.1:{}", options.port.unwrap_or(7070));
+    let server = poker::listen::Server::bind(&addr)?;
+    for signal in [SIGINT, SIGTERM] {
+        signal_hook::flag::register(signal, server.shutdown())?;
+    }
+
+    server.run().map(|_| 0)
+}
 
-    println!("{}", line);
+fn invalid(message: impl ToString) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.to_string())
 }
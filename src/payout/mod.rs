@@ -0,0 +1,288 @@
+use ::itertools::Itertools;
+
+use crate::card::Card;
+use crate::card::Suit;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Player {
+    /// Chips put in over the whole hand.
+    pub contributed: u64,
+    pub folded: bool,
+    /// Hole cards, only needed for `OddChip::HighestCard`.
+    pub cards: Vec<Card>,
+}
+
+/// Who receives the chips left over when a pot does not split evenly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OddChip {
+    /// First winner clockwise from the button.
+    LeftOfButton,
+    /// Winner holding the highest card, suits ranked spades, hearts,
+    /// diamonds, clubs when ranks are equal.
+    HighestCard,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pot {
+    pub amount: u64,
+    pub eligible: Vec<usize>,
+}
+
+/// Main pot first, then side pots in the order the all-ins happened.
+pub fn pots(players: &[Player]) -> Vec<Pot> {
+    let levels = players
+        .iter()
+        .filter(|player| !player.folded)
+        .map(|player| player.contributed)
+        .unique()
+        .sorted()
+        .collect::<Vec<_>>();
+
+    let mut previous = 0;
+    let mut pots = vec![];
+
+    for (i, level) in levels.iter().enumerate() {
+        // Chips of folded players above the last level still belong to the pot.
+        let cap = if i + 1 == levels.len() {
+            u64::MAX
+        } else {
+            *level
+        };
+
+        let amount = players
+            .iter()
+            .map(|player| player.contributed.min(cap) - player.contributed.min(previous))
+            .sum();
+
+        let eligible = (0..players.len())
+            .filter(|i| !players[*i].folded && players[*i].contributed >= *level)
+            .collect();
+
+        pots.push(Pot { amount, eligible });
+        previous = *level;
+    }
+
+    pots
+}
+
+/// Splits every pot between its best eligible hands. `ranking` holds groups
+/// of tied seats from the best hand to the worst, as `Engine::showdown`
+/// returns. The result is indexed by seat and sums to the total pot.
+pub fn payout(
+    players: &[Player],
+    ranking: &[Vec<usize>],
+    button: usize,
+    odd_chip: OddChip,
+) -> Vec<u64> {
+    split(&pots(players), players, ranking, button, odd_chip)
+}
+
+/// Like `payout`, for pots that were adjusted after `pots` built them, e.g.
+/// with the rake taken out.
+pub fn split(
+    pots: &[Pot],
+    players: &[Player],
+    ranking: &[Vec<usize>],
+    button: usize,
+    odd_chip: OddChip,
+) -> Vec<u64> {
+    let mut winnings = vec![0; players.len()];
+
+    for pot in pots {
+        let winners = ranking
+            .iter()
+            .map(|group| {
+                group
+                    .iter()
+                    .copied()
+                    .filter(|seat| pot.eligible.contains(seat))
+                    .collect::<Vec<_>>()
+            })
+            .find(|group| !group.is_empty())
+            .unwrap_or_else(|| pot.eligible.clone());
+
+        if winners.is_empty() {
+            continue;
+        }
+
+        let winners = order_for_odd_chips(players, winners, button, odd_chip);
+        let share = pot.amount / winners.len() as u64;
+        let remainder = (pot.amount % winners.len() as u64) as usize;
+
+        for (i, seat) in winners.iter().enumerate() {
+            winnings[*seat] += share + if i < remainder { 1 } else { 0 };
+        }
+    }
+
+    winnings
+}
+
+fn order_for_odd_chips(
+    players: &[Player],
+    winners: Vec<usize>,
+    button: usize,
+    odd_chip: OddChip,
+) -> Vec<usize> {
+    let n = players.len();
+
+    match odd_chip {
+        OddChip::LeftOfButton => winners
+            .into_iter()
+            .sorted_by_key(|seat| (seat + n - button - 1) % n)
+            .collect(),
+        OddChip::HighestCard => winners
+            .into_iter()
+            .sorted_by_key(|seat| {
+                let highest = players[*seat]
+                    .cards
+                    .iter()
+                    .map(|card| (card.0, suit_order(card.1)))
+                    .max();
+
+                std::cmp::Reverse(highest)
+            })
+            .collect(),
+    }
+}
+
+fn suit_order(suit: Suit) -> u8 {
+    match suit {
+        Suit::Clubs => 0,
+        Suit::Diamonds => 1,
+        Suit::Hearts => 2,
+        Suit::Spades => 3,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::card::parse_cards;
+    use crate::payout::payout;
+    use crate::payout::pots;
+    use crate::payout::OddChip;
+    use crate::payout::Player;
+    use crate::payout::Pot;
+
+    fn player(contributed: u64, folded: bool) -> Player {
+        Player {
+            contributed,
+            folded,
+            cards: vec![],
+        }
+    }
+
+    #[test]
+    fn test_single_pot() {
+        let players = vec![player(10, false), player(10, false), player(4, true)];
+
+        assert_eq!(
+            pots(&players),
+            vec![Pot {
+                amount: 24,
+                eligible: vec![0, 1]
+            }]
+        );
+    }
+
+    #[test]
+    fn test_side_pots() {
+        let players = vec![
+            player(50, false),
+            player(100, false),
+            player(100, false),
+            player(30, true),
+        ];
+
+        assert_eq!(
+            pots(&players),
+            vec![
+                Pot {
+                    amount: 180,
+                    eligible: vec![0, 1, 2]
+                },
+                Pot {
+                    amount: 100,
+                    eligible: vec![1, 2]
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_folded_excess_stays_in_pot() {
+        let players = vec![player(20, false), player(20, false), player(60, true)];
+
+        assert_eq!(
+            pots(&players),
+            vec![Pot {
+                amount: 100,
+                eligible: vec![0, 1]
+            }]
+        );
+    }
+
+    #[test]
+    fn test_payout_side_pot_winner() {
+        let players = vec![player(50, false), player(100, false), player(100, false)];
+        let ranking = vec![vec![0], vec![2], vec![1]];
+
+        let winnings = payout(&players, &ranking, 0, OddChip::LeftOfButton);
+
+        assert_eq!(winnings, vec![150, 0, 100]);
+        assert_eq!(winnings.iter().sum::<u64>(), 250);
+    }
+
+    #[test]
+    fn test_payout_odd_chip_left_of_button() {
+        let players = vec![player(5, false), player(5, false), player(5, false)];
+        let ranking = vec![vec![0, 2], vec![1]];
+
+        assert_eq!(
+            payout(&players, &ranking, 0, OddChip::LeftOfButton),
+            vec![7, 0, 8]
+        );
+        assert_eq!(
+            payout(&players, &ranking, 2, OddChip::LeftOfButton),
+            vec![8, 0, 7]
+        );
+    }
+
+    #[test]
+    fn test_payout_odd_chip_highest_card() {
+        let players = vec![
+            Player {
+                contributed: 5,
+                folded: false,
+                cards: parse_cards("AhKd"),
+            },
+            player(5, false),
+            Player {
+                contributed: 5,
+                folded: false,
+                cards: parse_cards("AsKc"),
+            },
+        ];
+        let ranking = vec![vec![0, 2], vec![1]];
+
+        assert_eq!(
+            payout(&players, &ranking, 0, OddChip::HighestCard),
+            vec![7, 0, 8]
+        );
+    }
+
+    #[test]
+    fn test_payout_sums_to_pot() {
+        let players = vec![
+            player(7, false),
+            player(23, false),
+            player(23, false),
+            player(11, true),
+            player(3, false),
+        ];
+        let ranking = vec![vec![4, 1], vec![0, 2]];
+
+        let winnings = payout(&players, &ranking, 3, OddChip::LeftOfButton);
+
+        assert_eq!(winnings.iter().sum::<u64>(), 67);
+    }
+}
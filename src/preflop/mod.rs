@@ -0,0 +1,207 @@
+pub mod table;
+pub use table::EquityTable;
+
+use std::fmt;
+use std::io;
+use std::str::FromStr;
+
+use crate::card::Card;
+use crate::card::Rank;
+use crate::card::Suit;
+use crate::game::HandOf2;
+
+/// One of the 169 strategically distinct starting hands, e.g. `AKs`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HandClass {
+    pub high: Rank,
+    pub low: Rank,
+    pub suited: bool,
+}
+
+impl HandClass {
+    pub const COUNT: usize = 169;
+
+    /// Classes are laid out on the usual 13x13 grid: pairs on the diagonal,
+    /// suited hands above it and offsuit hands below it.
+    pub fn index(&self) -> usize {
+        let (high, low) = (self.high as usize, self.low as usize);
+
+        if self.suited {
+            high * 13 + low
+        } else {
+            low * 13 + high
+        }
+    }
+
+    pub fn from_index(index: usize) -> Self {
+        assert!(index < Self::COUNT, "hand class index out of range");
+
+        let (row, col) = (index / 13, index % 13);
+
+        if row > col {
+            Self {
+                high: Rank::ALL[row],
+                low: Rank::ALL[col],
+                suited: true,
+            }
+        } else {
+            Self {
+                high: Rank::ALL[col],
+                low: Rank::ALL[row],
+                suited: false,
+            }
+        }
+    }
+
+    pub fn all() -> Vec<Self> {
+        (0..Self::COUNT).map(Self::from_index).collect()
+    }
+
+    pub fn is_pair(&self) -> bool {
+        self.high == self.low
+    }
+
+    /// Every concrete two-card hand belonging to the class.
+    pub fn hands(&self) -> Vec<HandOf2> {
+        let mut hands = vec![];
+
+        for (i, suit_a) in Suit::ALL.iter().enumerate() {
+            for (j, suit_b) in Suit::ALL.iter().enumerate() {
+                let keep = if self.is_pair() {
+                    i < j
+                } else if self.suited {
+                    i == j
+                } else {
+                    i != j
+                };
+
+                if keep {
+                    hands.push(HandOf2([Card(self.high, *suit_a), Card(self.low, *suit_b)]));
+                }
+            }
+        }
+
+        hands
+    }
+}
+
+impl From<HandOf2> for HandClass {
+    fn from(hand: HandOf2) -> Self {
+        let [a, b] = hand.0;
+        let (high, low) = if a.0 >= b.0 { (a.0, b.0) } else { (b.0, a.0) };
+
+        Self {
+            high,
+            low,
+            suited: high != low && a.1 == b.1,
+        }
+    }
+}
+
+impl FromStr for HandClass {
+    type Err = std::io::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let chars = s.chars().map(|c| c.to_string()).collect::<Vec<_>>();
+
+        let (a, b, suited) = match chars.as_slice() {
+            [a, b] if a == b => (a, b, false),
+            [a, b, s] if a != b && s == "s" => (a, b, true),
+            [a, b, s] if a != b && s == "o" => (a, b, false),
+            _ => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("unknown hand class value: {}", s),
+                ))
+            }
+        };
+
+        let (a, b) = (Rank::from_str(a)?, Rank::from_str(b)?);
+        let (high, low) = if a >= b { (a, b) } else { (b, a) };
+
+        Ok(Self { high, low, suited })
+    }
+}
+
+impl fmt::Display for HandClass {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_pair() {
+            write!(f, "{}{}", self.high, self.low)
+        } else if self.suited {
+            write!(f, "{}{}s", self.high, self.low)
+        } else {
+            write!(f, "{}{}o", self.high, self.low)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use ::itertools::Itertools;
+
+    use crate::card::Rank;
+    use crate::game::HandOf2;
+    use crate::preflop::HandClass;
+
+    #[test]
+    fn test_index_roundtrip() {
+        let classes = HandClass::all();
+
+        assert_eq!(classes.len(), 169);
+        assert_eq!(classes.iter().unique().count(), 169);
+        assert!(classes
+            .iter()
+            .enumerate()
+            .all(|(i, class)| class.index() == i));
+    }
+
+    #[test]
+    fn test_hands() {
+        let count = |s| HandClass::from_str(s).unwrap().hands().len();
+
+        assert_eq!(count("AA"), 6);
+        assert_eq!(count("AKs"), 4);
+        assert_eq!(count("AKo"), 12);
+        assert_eq!(
+            HandClass::all()
+                .iter()
+                .map(|class| class.hands().len())
+                .sum::<usize>(),
+            1326
+        );
+    }
+
+    #[test]
+    fn test_from_hand_of_2() {
+        let class = |s| HandClass::from(HandOf2::from_str(s).unwrap());
+
+        assert_eq!(class("AhKh"), HandClass::from_str("AKs").unwrap());
+        assert_eq!(class("KhAd"), HandClass::from_str("AKo").unwrap());
+        assert_eq!(class("7c7d"), HandClass::from_str("77").unwrap());
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(
+            HandClass::from_str("KAs").unwrap(),
+            HandClass {
+                high: Rank::Ace,
+                low: Rank::King,
+                suited: true
+            }
+        );
+
+        for s in &["", "A", "AAs", "AKx", "AKso", "XKs"] {
+            assert!(HandClass::from_str(s).is_err());
+        }
+    }
+
+    #[test]
+    fn test_to_string() {
+        assert_eq!(HandClass::from_str("AA").unwrap().to_string(), "AA");
+        assert_eq!(HandClass::from_str("T9s").unwrap().to_string(), "T9s");
+        assert_eq!(HandClass::from_str("72o").unwrap().to_string(), "72o");
+    }
+}
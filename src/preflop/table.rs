@@ -0,0 +1,296 @@
+use std::cmp::Ordering;
+use std::convert::TryInto;
+use std::io;
+use std::sync::OnceLock;
+
+use ::rand::Rng;
+use ::rand::SeedableRng;
+use ::rand_chacha::ChaCha8Rng;
+
+use crate::card::Card;
+use crate::card::Rank;
+use crate::card::Suit;
+use crate::game::Board;
+use crate::game::Game;
+use crate::preflop::HandClass;
+
+const MAGIC: &[u8; 4] = b"PKEQ";
+const VERSION: u8 = 2;
+const HEADER_LEN: usize = 4 + 1 + 4 + 8;
+const PAIRS: usize = HandClass::COUNT * (HandClass::COUNT + 1) / 2;
+
+static EMBEDDED: OnceLock<EquityTable> = OnceLock::new();
+
+/// Heads-up all-in equities between the 169 hand classes.
+///
+/// The binary format is a header (`PKEQ`, format version, boards dealt and
+/// seed, little-endian) followed by the upper triangle of the class
+/// matrix and then every class against a random hand, each entry a
+/// little-endian `u16` scaled so that `u16::MAX` is 100% equity.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EquityTable {
+    boards: u32,
+    seed: u64,
+    pairs: Vec<u16>,
+    random: Vec<u16>,
+}
+
+impl EquityTable {
+    pub fn embedded() -> &'static Self {
+        EMBEDDED.get_or_init(|| Self::from_bytes(include_bytes!("equity.bin")).unwrap())
+    }
+
+    /// Monte Carlo simulation over `boards` random boards from a ChaCha8
+    /// stream seeded with `seed`. Each hand that misses a board is evaluated
+    /// once and played against every other, so a board settles every
+    /// matchup at once. The 200,000 boards of the embedded table leave
+    /// errors of about 0.1%.
+    pub fn generate(boards: u32, seed: u64) -> Self {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+
+        let hands = (0..HandClass::COUNT)
+            .flat_map(|i| {
+                let hands = HandClass::from_index(i).hands();
+
+                hands.into_iter().map(move |hand| (i, mask(&hand.0), hand))
+            })
+            .collect::<Vec<_>>();
+
+        // Points, two for a win and one for a tie, and games of the row
+        // class against the column class.
+        let mut points = vec![0u64; HandClass::COUNT * HandClass::COUNT];
+        let mut games = vec![0u64; HandClass::COUNT * HandClass::COUNT];
+
+        for _ in 0..boards {
+            let mut dealt = 0;
+            let board = Board((0..5).map(|_| deal(&mut rng, &mut dealt)).collect());
+
+            let live = hands
+                .iter()
+                .filter(|(_, cards, _)| cards & dealt == 0)
+                .map(|&(class, cards, hand)| {
+                    (class, cards, Game::texas_holdem_combination(&board, hand))
+                })
+                .collect::<Vec<_>>();
+
+            for (k, (hero, hero_cards, ours)) in live.iter().enumerate() {
+                for (villain, villain_cards, theirs) in &live[k + 1..] {
+                    if hero_cards & villain_cards != 0 {
+                        continue;
+                    }
+
+                    let won = match ours.cmp(theirs) {
+                        Ordering::Greater => 2,
+                        Ordering::Equal => 1,
+                        Ordering::Less => 0,
+                    };
+                    let (ij, ji) = (
+                        hero * HandClass::COUNT + villain,
+                        villain * HandClass::COUNT + hero,
+                    );
+
+                    points[ij] += won;
+                    points[ji] += 2 - won;
+                    games[ij] += 1;
+                    games[ji] += 1;
+                }
+            }
+        }
+
+        let mut pairs = Vec::with_capacity(PAIRS);
+
+        for i in 0..HandClass::COUNT {
+            for j in i..HandClass::COUNT {
+                let ij = i * HandClass::COUNT + j;
+
+                pairs.push(share(points[ij], games[ij]));
+            }
+        }
+
+        let random = (0..HandClass::COUNT)
+            .map(|i| {
+                let row = i * HandClass::COUNT..(i + 1) * HandClass::COUNT;
+
+                share(points[row.clone()].iter().sum(), games[row].iter().sum())
+            })
+            .collect();
+
+        Self {
+            boards,
+            seed,
+            pairs,
+            random,
+        }
+    }
+
+    pub fn boards(&self) -> u32 {
+        self.boards
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    pub fn equity(&self, hero: HandClass, villain: HandClass) -> f64 {
+        let (i, j) = (hero.index(), villain.index());
+
+        let value = if i <= j {
+            self.pairs[triangle(i, j)]
+        } else {
+            u16::MAX - self.pairs[triangle(j, i)]
+        };
+
+        scale(value)
+    }
+
+    pub fn equity_vs_random(&self, hero: HandClass) -> f64 {
+        scale(self.random[hero.index()])
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, io::Error> {
+        let invalid = |reason| io::Error::new(io::ErrorKind::InvalidData, reason);
+
+        if bytes.len() != HEADER_LEN + 2 * (PAIRS + HandClass::COUNT) {
+            return Err(invalid("unexpected equity table size"));
+        }
+
+        if &bytes[0..4] != MAGIC {
+            return Err(invalid("not an equity table"));
+        }
+
+        if bytes[4] != VERSION {
+            return Err(invalid("unsupported equity table version"));
+        }
+
+        let boards = u32::from_le_bytes(bytes[5..9].try_into().unwrap());
+        let seed = u64::from_le_bytes(bytes[9..17].try_into().unwrap());
+
+        let mut values = bytes[HEADER_LEN..]
+            .chunks(2)
+            .map(|chunk| u16::from_le_bytes(chunk.try_into().unwrap()))
+            .collect::<Vec<_>>();
+        let random = values.split_off(PAIRS);
+
+        Ok(Self {
+            boards,
+            seed,
+            pairs: values,
+            random,
+        })
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(HEADER_LEN + 2 * (PAIRS + HandClass::COUNT));
+
+        bytes.extend_from_slice(MAGIC);
+        bytes.push(VERSION);
+        bytes.extend_from_slice(&self.boards.to_le_bytes());
+        bytes.extend_from_slice(&self.seed.to_le_bytes());
+
+        for value in self.pairs.iter().chain(self.random.iter()) {
+            bytes.extend_from_slice(&value.to_le_bytes());
+        }
+
+        bytes
+    }
+}
+
+fn triangle(i: usize, j: usize) -> usize {
+    i * (2 * HandClass::COUNT + 1 - i) / 2 + (j - i)
+}
+
+fn scale(value: u16) -> f64 {
+    value as f64 / u16::MAX as f64
+}
+
+/// The equity of `points` out of `games`, even when no game was played.
+fn share(points: u64, games: u64) -> u16 {
+    if games == 0 {
+        return u16::MAX / 2;
+    }
+
+    ((points * u16::MAX as u64 + games) / (2 * games)) as u16
+}
+
+/// Cards as bits in the order of `card::all_cards`.
+fn mask(cards: &[Card]) -> u64 {
+    cards.iter().fold(0, |acc, card| {
+        acc | 1 << (card.0 as usize * 4 + card.1 as usize)
+    })
+}
+
+fn deal<R: Rng>(rng: &mut R, dealt: &mut u64) -> Card {
+    loop {
+        let i = rng.gen_range(0..52);
+
+        if *dealt & 1 << i == 0 {
+            *dealt |= 1 << i;
+
+            return Card(Rank::ALL[i / 4], Suit::ALL[i % 4]);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::claim::*;
+
+    use std::str::FromStr;
+
+    use super::triangle;
+    use super::PAIRS;
+    use crate::preflop::EquityTable;
+    use crate::preflop::HandClass;
+
+    #[test]
+    fn test_triangle() {
+        let mut expected = 0;
+
+        for i in 0..HandClass::COUNT {
+            for j in i..HandClass::COUNT {
+                assert_eq!(triangle(i, j), expected);
+                expected += 1;
+            }
+        }
+
+        assert_eq!(expected, PAIRS);
+    }
+
+    #[test]
+    fn test_generate_reproducible() {
+        let table = EquityTable::generate(2, 7);
+
+        assert_eq!(table, EquityTable::generate(2, 7));
+        assert_ne!(table, EquityTable::generate(2, 8));
+        assert_eq!(table.boards(), 2);
+    }
+
+    #[test]
+    fn test_bytes_roundtrip() {
+        let table = EquityTable::embedded();
+
+        assert_eq!(&EquityTable::from_bytes(&table.to_bytes()).unwrap(), table);
+        assert_err!(EquityTable::from_bytes(&table.to_bytes()[1..]));
+    }
+
+    #[test]
+    fn test_embedded_equity() {
+        let table = EquityTable::embedded();
+        let class = |s| HandClass::from_str(s).unwrap();
+
+        // Exact values from enumerating every board.
+        let aa_kk = table.equity(class("AA"), class("KK"));
+        assert_lt!((aa_kk - 0.8195).abs(), 0.005);
+        assert_lt!(
+            (table.equity(class("AKs"), class("QQ")) - 0.46).abs(),
+            0.005
+        );
+        assert_lt!(
+            (table.equity(class("KK"), class("AA")) + aa_kk - 1.0).abs(),
+            1e-9
+        );
+
+        assert_gt!(table.equity_vs_random(class("AA")), 0.8);
+        assert_lt!(table.equity_vs_random(class("72o")), 0.4);
+    }
+}
@@ -0,0 +1,328 @@
+//! An interactive session for exploring spots:
+//!
+//! - `rank <game line>`: the hands of a game from best to worst.
+//! - `eval <cards>`: the best combination in five to seven cards.
+//! - `equity <hand> vs <hand> [vs ...] [board <cards>]`: hold'em or Omaha
+//!   equity depending on the number of hole cards.
+//! - `describe [<board>]`: texture and nuts of a board, by default the last
+//!   one used.
+//! - `history`: the commands entered so far.
+//!
+//! Errors are printed and the session goes on until `quit` or end of input.
+
+use std::convert::TryInto;
+use std::io;
+use std::io::BufRead;
+use std::io::Write;
+
+use ::itertools::Itertools;
+use ::rand::SeedableRng;
+use ::rand_chacha::ChaCha8Rng;
+
+use crate::card::notation;
+use crate::card::Card;
+use crate::combination::Combination;
+use crate::game::equity::Spot;
+use crate::game::nuts::Nuts;
+use crate::game::nuts::Rules;
+use crate::game::texture::HighCardClass;
+use crate::game::texture::Pairing;
+use crate::game::texture::Suitedness;
+use crate::game::Board;
+use crate::game::Game;
+use crate::game::Variant;
+
+/// Runouts drawn when equity cannot be enumerated.
+const SAMPLES: usize = 10_000;
+
+const HELP: &str = "\
+rank <game> <board> <hands...>     rank hands, e.g. rank texas-holdem 4cKs4h8s7s Ad4s As9s
+eval <cards>                       best combination in 5 to 7 cards
+equity <hand> vs <hand> [board <cards>]
+describe [<board>]                 texture and nuts of a board
+history                            commands entered so far
+quit";
+
+pub struct Repl {
+    history: Vec<String>,
+    board: Option<Board>,
+    rng: ChaCha8Rng,
+    finished: bool,
+}
+
+impl Default for Repl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Repl {
+    pub fn new() -> Self {
+        Self {
+            history: vec![],
+            board: None,
+            rng: ChaCha8Rng::seed_from_u64(0),
+            finished: false,
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Prompts for commands on `output` until `quit` or end of `input`.
+    pub fn run<R: BufRead, W: Write>(&mut self, input: R, mut output: W) -> io::Result<()> {
+        let mut lines = input.lines();
+
+        while !self.finished {
+            write!(output, "poker> ")?;
+            output.flush()?;
+
+            let line = match lines.next() {
+                Some(line) => line?,
+                None => break,
+            };
+
+            match self.execute(&line) {
+                Ok(result) if result.is_empty() => {}
+                Ok(result) => writeln!(output, "{}", result)?,
+                Err(error) => writeln!(output, "error: {}", error)?,
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn execute(&mut self, line: &str) -> io::Result<String> {
+        let line = line.trim();
+        let (command, args) = line.split_once(' ').unwrap_or((line, ""));
+        let args = args.trim();
+
+        let result = match command {
+            "" => return Ok(String::new()),
+            "rank" => self.rank(args),
+            "eval" => eval(args),
+            "equity" => self.equity(args),
+            "describe" => self.describe(args),
+            "history" => Ok(self
+                .history
+                .iter()
+                .enumerate()
+                .map(|(i, line)| format!("{:>3}  {}", i + 1, line))
+                .join("\n")),
+            "help" => Ok(HELP.to_string()),
+            "quit" | "exit" => {
+                self.finished = true;
+                Ok(String::new())
+            }
+            _ => Err(invalid(format!("unknown command: {}, try help", command))),
+        };
+
+        self.history.push(line.to_string());
+
+        result
+    }
+
+    fn rank(&mut self, args: &str) -> io::Result<String> {
+        let game = crate::text::parse(args)?;
+
+        if let Game::TexasHoldem(board, _) | Game::OmahaHoldem(board, _) = &game {
+            self.board = Some(board.clone());
+        }
+
+        let ranked = Game::rank_hands(game)
+            .into_iter()
+            .sorted_by(|(_, a), (_, b)| b.cmp(a))
+            .collect::<Vec<_>>();
+
+        Ok(ranked
+            .iter()
+            .map(|(named, combination)| {
+                let position = 1 + ranked
+                    .iter()
+                    .map(|(_, other)| other)
+                    .filter(|other| *other > combination)
+                    .dedup()
+                    .count();
+
+                format!("{:>2}. {:<16} {}", position, named.to_string(), combination)
+            })
+            .join("\n"))
+    }
+
+    fn equity(&mut self, args: &str) -> io::Result<String> {
+        let spot = Spot::parse(args)?;
+        let equity = spot.equity(SAMPLES, &mut self.rng);
+
+        if spot.board.len() >= 3 {
+            self.board = Some(Board(spot.board.clone()));
+        }
+
+        Ok(spot
+            .hands
+            .iter()
+            .zip(equity)
+            .map(|(hand, equity)| format!("{:<10} {:>6.2}%", hand.iter().join(""), equity * 100.0))
+            .join("\n"))
+    }
+
+    fn describe(&mut self, args: &str) -> io::Result<String> {
+        let board = if args.is_empty() {
+            self.board
+                .clone()
+                .ok_or_else(|| invalid("no board to describe yet"))?
+        } else {
+            let cards = cards(args)?;
+            if !(3..=5).contains(&cards.len()) {
+                return Err(invalid(format!("unexpected board size: {}", cards.len())));
+            }
+            Board(cards)
+        };
+
+        let texture = board.texture();
+        let pairing = match texture.pairing {
+            Pairing::Unpaired => "unpaired",
+            Pairing::Paired => "paired",
+            Pairing::TwoPaired => "two-paired",
+            Pairing::Trips => "trips",
+            Pairing::FullHouse => "full house",
+            Pairing::Quads => "quads",
+        };
+        let suitedness = match texture.suitedness {
+            Suitedness::Rainbow => "rainbow",
+            Suitedness::TwoTone => "two-tone",
+            Suitedness::FlushPossible => "flush possible",
+            Suitedness::Monotone => "monotone",
+        };
+        let high_card = match texture.high_card {
+            HighCardClass::Low => "low",
+            HighCardClass::Middle => "middle",
+            HighCardClass::Broadway => "broadway",
+            HighCardClass::Ace => "ace-high",
+        };
+        let nuts = Nuts::new(&board, Rules::TexasHoldem).nuts();
+
+        let description = format!(
+            "{}: {}, {}, {}, {} straights possible\nnuts: {} ({})",
+            board.0.iter().join(""),
+            pairing,
+            suitedness,
+            high_card,
+            texture.connectedness(),
+            nuts.cards.iter().join(""),
+            nuts.combination
+        );
+
+        self.board = Some(board);
+
+        Ok(description)
+    }
+}
+
+fn eval(args: &str) -> io::Result<String> {
+    let cards = cards(args)?;
+    if !(5..=7).contains(&cards.len()) {
+        return Err(invalid(format!(
+            "expected 5 to 7 cards, got {}",
+            cards.len()
+        )));
+    }
+
+    let (combination, best) = cards
+        .into_iter()
+        .combinations(5)
+        .map(|cards| {
+            let variant = Variant(cards.try_into().unwrap());
+            (Combination::from_variant(variant), variant)
+        })
+        .max_by(|(a, _), (b, _)| a.cmp(b))
+        .unwrap();
+
+    Ok(format!("{} ({})", combination, best.0.iter().join("")))
+}
+
+fn cards(raw: &str) -> io::Result<Vec<Card>> {
+    notation::cards(raw).ok_or_else(|| invalid(format!("invalid cards: {}", raw)))
+}
+
+fn invalid(message: impl ToString) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::repl::Repl;
+
+    #[test]
+    fn test_rank() {
+        let mut repl = Repl::new();
+
+        assert_eq!(
+            repl.execute("rank texas-holdem 4cKs4h8s7s alice:Ad4s Ac4d As9s")
+                .unwrap(),
+            " 1. As9s             flush, ace high\n 2. alice:Ad4s       three of a kind, fours\n 2. Ac4d             three of a kind, fours"
+        );
+    }
+
+    #[test]
+    fn test_eval() {
+        let mut repl = Repl::new();
+
+        assert_eq!(
+            repl.execute("eval AhKhQhJhTh").unwrap(),
+            "royal flush (AhKhQhJhTh)"
+        );
+        assert_eq!(
+            repl.execute("eval 2c 2d 9s 9h Kd 4c 9c").unwrap(),
+            "full house, nines full of twos (2c2d9s9h9c)"
+        );
+        assert!(repl.execute("eval AhKh").is_err());
+    }
+
+    #[test]
+    fn test_equity() {
+        let mut repl = Repl::new();
+
+        assert_eq!(
+            repl.execute("equity AhAd vs KcKs board 2c7d9h3s").unwrap(),
+            "AhAd        95.45%\nKcKs         4.55%"
+        );
+        assert!(repl.execute("equity AhAd vs AhKs").is_err());
+        assert!(repl.execute("equity AhAd vs KcKsQcQs").is_err());
+        assert!(repl.execute("equity AhAd").is_err());
+    }
+
+    #[test]
+    fn test_describe() {
+        let mut repl = Repl::new();
+
+        assert!(repl.execute("describe").is_err());
+        assert_eq!(
+            repl.execute("describe Kh Qh Jh").unwrap(),
+            "KhQhJh: unpaired, monotone, broadway, 2 straights possible\nnuts: ThAh (royal flush)"
+        );
+
+        repl.execute("equity AsAd vs KcKs board 9c8c7d").unwrap();
+        assert!(repl.execute("describe").unwrap().starts_with("9c8c7d: "));
+    }
+
+    #[test]
+    fn test_history_and_errors() {
+        let mut repl = Repl::new();
+        let mut output = vec![];
+
+        repl.run(
+            "eval AhKh\nfold\n\nhistory\nquit\neval AhKhQhJhTh\n".as_bytes(),
+            &mut output,
+        )
+        .unwrap();
+
+        assert!(repl.is_finished());
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "poker> error: expected 5 to 7 cards, got 2\n\
+             poker> error: unknown command: fold, try help\n\
+             poker> poker>   1  eval AhKh\n  2  fold\npoker> "
+        );
+    }
+}
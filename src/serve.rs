@@ -0,0 +1,498 @@
+//! The evaluator over HTTP, one JSON body in and one out:
+//!
+//! - `POST /rank`: a game as read by `json::process`, answered the same way.
+//! - `POST /evaluate`: `{"cards": ["Ah", ...]}` with five cards.
+//! - `POST /equity`: `{"game": "texas-holdem", "board": [...], "hands": [[...], ...]}`
+//!   with an optional number of `samples` and a `seed`.
+//!
+//! Malformed requests get a 4xx status and `{"error": "..."}`.
+
+use std::convert::TryInto;
+use std::io;
+use std::io::BufRead;
+use std::io::BufReader;
+use std::io::Read;
+use std::io::Write;
+use std::net::TcpListener;
+use std::net::TcpStream;
+use std::thread;
+use std::time::Duration;
+
+use ::rand::SeedableRng;
+use ::rand_chacha::ChaCha8Rng;
+use ::serde::Deserialize;
+use ::serde_json::json;
+
+use crate::card::Card;
+use crate::combination::Combination;
+use crate::game::equity;
+use crate::game::Game;
+use crate::game::HandOf2;
+use crate::game::HandOf4;
+use crate::game::Kind;
+use crate::game::Variant;
+
+/// Connections served at once; more wait in the listen backlog.
+const WORKERS: usize = 8;
+/// How long a read or a write on a connection may block.
+const TIMEOUT: Duration = Duration::from_secs(10);
+/// Longest request or header line accepted, line break included.
+const MAX_LINE: usize = 8 * 1024;
+const MAX_HEADERS: usize = 100;
+/// Largest request body accepted.
+const MAX_BODY: usize = 1 << 20;
+/// Runouts drawn for equity when the request does not say.
+const SAMPLES: usize = 10_000;
+/// Kept low so that one request cannot hold a worker for long.
+const MAX_SAMPLES: usize = 100_000;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+impl Response {
+    fn ok(body: ::serde_json::Value) -> Self {
+        Self {
+            status: 200,
+            body: body.to_string(),
+        }
+    }
+
+    fn error(status: u16, message: impl ToString) -> Self {
+        Self {
+            status,
+            body: json!({ "error": message.to_string() }).to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct EvaluateRequest {
+    cards: Vec<Card>,
+}
+
+#[derive(Debug, Deserialize)]
+struct EquityRequest {
+    game: Kind,
+    #[serde(default)]
+    board: Vec<Card>,
+    hands: Vec<Vec<Card>>,
+    samples: Option<usize>,
+    #[serde(default)]
+    seed: u64,
+}
+
+/// Serves requests on `addr` until the process is stopped, on `WORKERS`
+/// threads each taking one connection at a time.
+pub fn run(addr: &str) -> io::Result<()> {
+    let listener = TcpListener::bind(addr)?;
+    let listener = &listener;
+
+    thread::scope(|scope| {
+        let workers = (0..WORKERS)
+            .map(|_| scope.spawn(move || serve(listener)))
+            .collect::<Vec<_>>();
+
+        workers
+            .into_iter()
+            .try_for_each(|worker| worker.join().unwrap())
+    })
+}
+
+fn serve(listener: &TcpListener) -> io::Result<()> {
+    for stream in listener.incoming() {
+        if let Err(error) = stream.and_then(connection) {
+            eprintln!("connection failed: {}", error);
+        }
+    }
+
+    Ok(())
+}
+
+fn connection(stream: TcpStream) -> io::Result<()> {
+    stream.set_read_timeout(Some(TIMEOUT))?;
+    stream.set_write_timeout(Some(TIMEOUT))?;
+
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let response = match read_request(&mut reader)? {
+        Ok((method, path, body)) => route(&method, &path, &body),
+        Err(response) => response,
+    };
+
+    write_response(stream, &response)
+}
+
+type Request = (String, String, String);
+
+/// The method, path and body of the next request, or the response telling
+/// the client why it cannot be read.
+fn read_request<R: BufRead>(reader: &mut R) -> io::Result<Result<Request, Response>> {
+    let mut line = String::new();
+    if !read_line(reader, &mut line)? {
+        return Ok(Err(Response::error(414, "request line too long")));
+    }
+
+    let (method, path) = match line.split_whitespace().collect::<Vec<_>>()[..] {
+        [method, path, version] if version.starts_with("HTTP/") => {
+            (method.to_string(), path.to_string())
+        }
+        _ => return Ok(Err(Response::error(400, "malformed request line"))),
+    };
+
+    let mut length = 0;
+    let mut headers = 0;
+    loop {
+        if !read_line(reader, &mut line)? {
+            return Ok(Err(Response::error(431, "header line too long")));
+        }
+        if line.trim().is_empty() {
+            break;
+        }
+
+        headers += 1;
+        if headers > MAX_HEADERS {
+            return Ok(Err(Response::error(431, "too many headers")));
+        }
+
+        if let Some((name, value)) = line.split_once(':') {
+            if name.trim().eq_ignore_ascii_case("content-length") {
+                length = match value.trim().parse() {
+                    Ok(length) => length,
+                    Err(_) => return Ok(Err(Response::error(400, "invalid content length"))),
+                };
+            }
+        }
+    }
+
+    if length > MAX_BODY {
+        return Ok(Err(Response::error(413, "request body too large")));
+    }
+
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body)?;
+
+    match String::from_utf8(body) {
+        Ok(body) => Ok(Ok((method, path, body))),
+        Err(_) => Ok(Err(Response::error(400, "request body is not UTF-8"))),
+    }
+}
+
+/// Replaces `line` with the next line of the request, `false` when it is
+/// longer than `MAX_LINE`. At the end of the input `line` is left empty.
+fn read_line<R: BufRead>(reader: &mut R, line: &mut String) -> io::Result<bool> {
+    line.clear();
+    let read = reader.take(MAX_LINE as u64).read_line(line)?;
+
+    Ok(read < MAX_LINE || line.ends_with('\n'))
+}
+
+fn write_response<W: Write>(mut writer: W, response: &Response) -> io::Result<()> {
+    let reason = match response.status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        413 => "Payload Too Large",
+        414 => "URI Too Long",
+        431 => "Request Header Fields Too Large",
+        _ => "Error",
+    };
+
+    write!(
+        writer,
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        response.status,
+        reason,
+        response.body.len(),
+        response.body
+    )?;
+
+    writer.flush()
+}
+
+pub fn route(method: &str, path: &str, body: &str) -> Response {
+    let handler = match path {
+        "/rank" => rank,
+        "/evaluate" => evaluate,
+        "/equity" => compute_equity,
+        _ => return Response::error(404, format!("unknown path: {}", path)),
+    };
+
+    if method != "POST" {
+        return Response::error(405, format!("{} expects POST", path));
+    }
+
+    match handler(body) {
+        Ok(response) => response,
+        Err(error) => Response::error(400, error),
+    }
+}
+
+fn rank(body: &str) -> io::Result<Response> {
+    let game = ::serde_json::from_str::<Game>(body)?;
+
+    Ok(Response::ok(::serde_json::to_value(crate::json::rank(
+        game,
+    ))?))
+}
+
+fn evaluate(body: &str) -> io::Result<Response> {
+    let request = ::serde_json::from_str::<EvaluateRequest>(body)?;
+    distinct(&request.cards)?;
+
+    let cards: [Card; 5] = request
+        .cards
+        .try_into()
+        .map_err(|cards: Vec<Card>| invalid(format!("expected 5 cards, got {}", cards.len())))?;
+    let combination = Combination::from_variant(Variant(cards));
+
+    Ok(Response::ok(json!({
+        "combination": combination,
+        "category": combination.category(),
+    })))
+}
+
+fn compute_equity(body: &str) -> io::Result<Response> {
+    let request = ::serde_json::from_str::<EquityRequest>(body)?;
+    distinct(
+        &request
+            .board
+            .iter()
+            .chain(request.hands.iter().flatten())
+            .copied()
+            .collect::<Vec<_>>(),
+    )?;
+
+    if request.board.len() > 5 {
+        return Err(invalid(format!("board has {} cards", request.board.len())));
+    }
+    if request.hands.len() < 2 {
+        return Err(invalid("equity needs at least 2 hands"));
+    }
+
+    let samples = request.samples.unwrap_or(SAMPLES);
+    if samples == 0 || samples > MAX_SAMPLES {
+        return Err(invalid(format!(
+            "samples must be between 1 and {}",
+            MAX_SAMPLES
+        )));
+    }
+
+    let mut rng = ChaCha8Rng::seed_from_u64(request.seed);
+
+    let equity = match request.game {
+        Kind::TexasHoldem => {
+            let hands = hands(&request.hands, HandOf2)?;
+            equity::texas_holdem(&request.board, &hands, samples, &mut rng)
+        }
+        Kind::OmahaHoldem => {
+            let hands = hands(&request.hands, HandOf4)?;
+            equity::omaha_holdem(&request.board, &hands, samples, &mut rng)
+        }
+        Kind::FiveCardDraw | Kind::ThreeCardPoker => {
+            return Err(invalid("equity needs a game with a board"))
+        }
+    };
+
+    Ok(Response::ok(json!({ "equity": equity })))
+}
+
+fn hands<H, const N: usize>(raw: &[Vec<Card>], hand: fn([Card; N]) -> H) -> io::Result<Vec<H>> {
+    raw.iter()
+        .map(|cards| {
+            let cards: [Card; N] = cards.clone().try_into().map_err(|cards: Vec<Card>| {
+                invalid(format!(
+                    "expected {} cards in a hand, got {}",
+                    N,
+                    cards.len()
+                ))
+            })?;
+
+            Ok(hand(cards))
+        })
+        .collect()
+}
+
+fn distinct(cards: &[Card]) -> io::Result<()> {
+    match cards
+        .iter()
+        .enumerate()
+        .find(|(i, card)| cards[..*i].contains(card))
+        .map(|(_, card)| card)
+    {
+        Some(card) => Err(invalid(format!("card {} is dealt twice", card))),
+        None => Ok(()),
+    }
+}
+
+fn invalid(message: impl ToString) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use crate::serve::read_request;
+    use crate::serve::route;
+    use crate::serve::write_response;
+    use crate::serve::Response;
+    use crate::serve::MAX_HEADERS;
+    use crate::serve::MAX_LINE;
+    use crate::serve::MAX_SAMPLES;
+
+    fn error(response: &Response) -> String {
+        let body = ::serde_json::from_str::<::serde_json::Value>(&response.body).unwrap();
+
+        body["error"].as_str().unwrap().to_string()
+    }
+
+    #[test]
+    fn test_rank() {
+        let response = route(
+            "POST",
+            "/rank",
+            r#"{"game":"texas-holdem","board":["4c","Ks","4h","8s","7s"],"hands":[{"name":"alice","cards":["Ad","4s"]},["Ac","9d"]]}"#,
+        );
+
+        assert_eq!(response.status, 200);
+        assert!(response.body.contains(r#""name":"alice""#));
+        assert!(response.body.contains(r#""category":"three_of_a_kind""#));
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let response = route(
+            "POST",
+            "/evaluate",
+            r#"{"cards":["Ah","Kh","Qh","Jh","Th"]}"#,
+        );
+
+        assert_eq!(response.status, 200);
+        assert!(response.body.contains(r#""category":"straight_flush""#));
+    }
+
+    #[test]
+    fn test_equity() {
+        let response = route(
+            "POST",
+            "/equity",
+            r#"{"game":"texas-holdem","board":["2c","7d","9h","3s","Kh"],"hands":[["Ah","Ad"],["Kc","Ks"]]}"#,
+        );
+
+        assert_eq!(
+            response,
+            Response::ok(::serde_json::json!({ "equity": [0.0, 1.0] }))
+        );
+    }
+
+    #[test]
+    fn test_malformed_cards() {
+        let response = route(
+            "POST",
+            "/evaluate",
+            r#"{"cards":["Ah","Kh","Qh","Jh","Tx"]}"#,
+        );
+        assert_eq!(response.status, 400);
+        assert!(error(&response).contains("Tx"));
+
+        let response = route("POST", "/evaluate", r#"{"cards":["Ah","Kh","Qh","Jh"]}"#);
+        assert_eq!(response.status, 400);
+        assert_eq!(error(&response), "expected 5 cards, got 4");
+
+        let response = route(
+            "POST",
+            "/equity",
+            r#"{"game":"texas-holdem","hands":[["Ah","Ad"],["Ah","Ks"]]}"#,
+        );
+        assert_eq!(response.status, 400);
+        assert_eq!(error(&response), "card Ah is dealt twice");
+
+        let response = route("POST", "/rank", "not json");
+        assert_eq!(response.status, 400);
+    }
+
+    #[test]
+    fn test_unknown_route() {
+        assert_eq!(route("POST", "/nope", "").status, 404);
+        assert_eq!(route("GET", "/rank", "").status, 405);
+    }
+
+    #[test]
+    fn test_read_request() {
+        let mut reader = Cursor::new(
+            "POST /evaluate HTTP/1.1\r\nHost: localhost\r\nContent-Length: 7\r\n\r\n{\"a\":1}",
+        );
+
+        assert_eq!(
+            read_request(&mut reader).unwrap().unwrap(),
+            (
+                "POST".to_string(),
+                "/evaluate".to_string(),
+                "{\"a\":1}".to_string()
+            )
+        );
+
+        let mut reader = Cursor::new("garbage\r\n\r\n");
+        assert_eq!(read_request(&mut reader).unwrap().unwrap_err().status, 400);
+    }
+
+    #[test]
+    fn test_read_request_limits() {
+        let status = |request: String| {
+            read_request(&mut Cursor::new(request))
+                .unwrap()
+                .unwrap_err()
+                .status
+        };
+
+        let path = "a".repeat(MAX_LINE);
+        assert_eq!(status(format!("POST /{} HTTP/1.1\r\n\r\n", path)), 414);
+
+        let header = format!("X-Long: {}\r\n", path);
+        assert_eq!(
+            status(format!("POST /rank HTTP/1.1\r\n{}\r\n", header)),
+            431
+        );
+
+        let headers = "X-Many: 1\r\n".repeat(MAX_HEADERS + 1);
+        assert_eq!(
+            status(format!("POST /rank HTTP/1.1\r\n{}\r\n", headers)),
+            431
+        );
+
+        let headers = "X-Many: 1\r\n".repeat(MAX_HEADERS);
+        let request = format!("POST /rank HTTP/1.1\r\n{}\r\n", headers);
+        assert!(read_request(&mut Cursor::new(request)).unwrap().is_ok());
+    }
+
+    #[test]
+    fn test_max_samples() {
+        let request = |samples: usize| {
+            route(
+                "POST",
+                "/equity",
+                &format!(
+                    r#"{{"game":"texas-holdem","hands":[["Ah","Ad"],["Kc","Ks"]],"samples":{}}}"#,
+                    samples
+                ),
+            )
+        };
+
+        assert_eq!(request(MAX_SAMPLES + 1).status, 400);
+        assert_eq!(request(100).status, 200);
+    }
+
+    #[test]
+    fn test_write_response() {
+        let mut output = vec![];
+        write_response(&mut output, &Response::error(404, "unknown path: /")).unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "HTTP/1.1 404 Not Found\r\nContent-Type: application/json\r\nContent-Length: 27\r\nConnection: close\r\n\r\n{\"error\":\"unknown path: /\"}"
+        );
+    }
+}
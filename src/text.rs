@@ -0,0 +1,161 @@
+//! The plain line format of the CLI:
+//!
+//! `texas-holdem 4cKs4h8s7s alice:Ad4s Ac4d As9s` in,
+//! `Ac4d=alice:Ad4s As9s` out, from the worst hand to the best.
+//!
+//! Three card poker hands are then settled against the dealer with the
+//! default paytable, each with the units won in the order of the input:
+//! `three-card-poker Qh7d2c bob:5c6d7s AhKhJh` gets
+//! `AhKhJh bob:5c6d7s | bob:5c6d7s +9 AhKhJh +5`.
+
+use std::io;
+use std::str::FromStr;
+
+use ::itertools::Itertools;
+
+use crate::card::notation;
+use crate::card::notation::Style;
+use crate::card::Card;
+use crate::diagnostic;
+use crate::diagnostic::error;
+use crate::game::three_card_poker::Paytable;
+use crate::game::Game;
+use crate::game::Named;
+
+pub fn process(line: &str, style: Style) -> io::Result<String> {
+    Ok(rank(parse(line)?, style))
+}
+
+/// `Game::from_str`, also rejecting cards dealt twice. Errors carry a
+/// `Diagnostic` pointing at the offending token.
+pub fn parse(line: &str) -> io::Result<Game> {
+    let game = Game::from_str(line)?;
+
+    let mut seen = vec![];
+    for (column, token) in diagnostic::tokens(line).into_iter().skip(1) {
+        let raw = token.rsplit_once(':').map_or(token, |(_, raw)| raw);
+
+        for card in notation::cards(raw).unwrap_or_default() {
+            if seen.contains(&card) {
+                return Err(error(
+                    column,
+                    token,
+                    format!("card {} is dealt twice", card),
+                ));
+            }
+            seen.push(card);
+        }
+    }
+
+    Ok(game)
+}
+
+pub fn rank(game: Game, style: Style) -> String {
+    let outcomes = game.settle_three_card_poker(&Paytable::default());
+    let ranked_hands = Game::rank_hands(game);
+    let settled = ranked_hands
+        .iter()
+        .zip(&outcomes)
+        .map(|((hand, _), outcome)| format!("{} {:+}", styled(hand, style), outcome.total()))
+        .join(" ");
+    let grouped_hands = Game::group_hands(ranked_hands);
+    let sorted_hands = Game::sort_hands(grouped_hands);
+
+    let ranking = sorted_hands
+        .into_iter()
+        .map(|group| {
+            group
+                .into_iter()
+                .sorted_by_key(|hand| hand.to_string())
+                .map(|hand| styled(&hand, style))
+                .format("=")
+                .to_string()
+        })
+        .join(" ");
+
+    if settled.is_empty() {
+        ranking
+    } else {
+        format!("{} | {}", ranking, settled)
+    }
+}
+
+fn styled(hand: &Named<Vec<Card>>, style: Style) -> String {
+    let cards = hand.hand.iter().map(|card| card.styled(style)).join("");
+
+    match &hand.name {
+        Some(name) => format!("{}:{}", name, cards),
+        None => cards,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::card::notation::Style;
+    use crate::diagnostic;
+    use crate::text::parse;
+    use crate::text::process;
+
+    #[test]
+    fn test_process() {
+        assert_eq!(
+            process("texas-holdem 4cKs4h8s7s alice:Ad4s Ac4d As9s", Style::Ascii).unwrap(),
+            "Ac4d=alice:Ad4s As9s"
+        );
+        assert_eq!(
+            process("five-card-draw 2c3c4c5c7d AsKsQsJs10s", Style::Unicode).unwrap(),
+            "2♣3♣4♣5♣7♦ A♠K♠Q♠J♠T♠"
+        );
+        assert_eq!(
+            process("three-card-poker Qh7d2c bob:5c6d7s AhKhJh", Style::Ascii).unwrap(),
+            "AhKhJh bob:5c6d7s | bob:5c6d7s +9 AhKhJh +5"
+        );
+        assert_eq!(
+            process("texas-holdem Qs7c2d5h3h AhKh AsTd", Style::Ascii).unwrap(),
+            "AsTd AhKh"
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = |line: &str| parse(line).unwrap_err().to_string();
+
+        assert_eq!(error(""), "empty line");
+        assert_eq!(error("stud AhKd"), "unknown game: stud");
+        assert_eq!(error("texas-holdem"), "missing board");
+        assert_eq!(error("texas-holdem 4cKs"), "unexpected board size: 2");
+        assert_eq!(error("texas-holdem 4cKs4h AxKd"), "invalid hand: AxKd");
+        assert_eq!(
+            error("omaha-holdem 4cKs4h AhKd"),
+            "expected 4 cards in AhKd, got 2"
+        );
+        assert_eq!(error("texas-holdem 4cKs4h Ah4c"), "card 4c is dealt twice");
+        assert_eq!(error("five-card-draw"), "no hands");
+        assert_eq!(error("three-card-poker"), "missing dealer");
+        assert_eq!(
+            error("three-card-poker QhJd AhKd2c"),
+            "expected 3 cards in QhJd, got 2"
+        );
+    }
+
+    #[test]
+    fn test_parse_diagnostic() {
+        let diagnostic = |line: &str| {
+            let error = parse(line).unwrap_err();
+            let diagnostic = diagnostic::of(&error).unwrap();
+
+            (diagnostic.column, diagnostic.token.clone())
+        };
+
+        assert_eq!(diagnostic("  stud AhKd"), (3, "stud".to_string()));
+        assert_eq!(
+            diagnostic("texas-holdem 4cKs4h AhKd bob:AxKd"),
+            (26, "bob:AxKd".to_string())
+        );
+        assert_eq!(
+            diagnostic("texas-holdem 4cKs4h AhKd Ah4d"),
+            (26, "Ah4d".to_string())
+        );
+        assert_eq!(diagnostic("texas-holdem"), (13, "".to_string()));
+    }
+}
@@ -0,0 +1,184 @@
+//! Three-card hands, which rank differently from five-card ones: a straight
+//! beats a flush, three of a kind beats a straight and there is no two
+//! pairs or full house.
+
+use std::fmt;
+
+use ::itertools::Itertools;
+
+use crate::card::Card;
+use crate::card::Rank;
+use crate::combination::name;
+use crate::combination::plural;
+
+/// Variants go from the lowest to the highest, so the derived ordering is
+/// the ranking of the hands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(::serde::Serialize, ::serde::Deserialize),
+    serde(tag = "category", rename_all = "snake_case")
+)]
+pub enum ThreeCard {
+    /// Ranks from the highest.
+    HighCard {
+        ranks: [Rank; 3],
+    },
+    Pair {
+        rank: Rank,
+        kicker: Rank,
+    },
+    /// Ranks from the highest.
+    Flush {
+        ranks: [Rank; 3],
+    },
+    /// Identified by the highest rank, `Three` for ace-two-three.
+    Straight {
+        rank: Rank,
+    },
+    ThreeOfAKind {
+        rank: Rank,
+    },
+    StraightFlush {
+        rank: Rank,
+    },
+}
+
+impl ThreeCard {
+    pub fn from_cards(cards: [Card; 3]) -> Self {
+        let ranks = cards
+            .iter()
+            .map(|card| card.0)
+            .sorted_by(|a, b| b.cmp(a))
+            .collect::<Vec<_>>();
+        let ranks = [ranks[0], ranks[1], ranks[2]];
+
+        let flush = cards.iter().all(|card| card.1 == cards[0].1);
+        let straight = match ranks {
+            [Rank::Ace, Rank::Three, Rank::Two] => Some(Rank::Three),
+            [high, middle, low]
+                if high as usize == middle as usize + 1 && middle as usize == low as usize + 1 =>
+            {
+                Some(high)
+            }
+            _ => None,
+        };
+
+        match (straight, flush, ranks) {
+            (Some(rank), true, _) => Self::StraightFlush { rank },
+            (_, _, [high, middle, low]) if high == middle && middle == low => {
+                Self::ThreeOfAKind { rank: high }
+            }
+            (Some(rank), false, _) => Self::Straight { rank },
+            (None, true, _) => Self::Flush { ranks },
+            (_, _, [high, middle, low]) if high == middle => Self::Pair {
+                rank: high,
+                kicker: low,
+            },
+            (_, _, [high, middle, low]) if middle == low => Self::Pair {
+                rank: middle,
+                kicker: high,
+            },
+            _ => Self::HighCard { ranks },
+        }
+    }
+
+    pub fn category(&self) -> &'static str {
+        match self {
+            Self::HighCard { .. } => "high_card",
+            Self::Pair { .. } => "pair",
+            Self::Flush { .. } => "flush",
+            Self::Straight { .. } => "straight",
+            Self::ThreeOfAKind { .. } => "three_of_a_kind",
+            Self::StraightFlush { .. } => "straight_flush",
+        }
+    }
+}
+
+/// Reads like `Combination`: `pair of nines`.
+impl fmt::Display for ThreeCard {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::HighCard { ranks } => write!(f, "high card, {}", name(ranks[0])),
+            Self::Pair { rank, .. } => write!(f, "pair of {}", plural(*rank)),
+            Self::Flush { ranks } => write!(f, "flush, {} high", name(ranks[0])),
+            Self::Straight { rank } => write!(f, "straight, {} high", name(*rank)),
+            Self::ThreeOfAKind { rank } => write!(f, "three of a kind, {}", plural(*rank)),
+            Self::StraightFlush { rank } => write!(f, "straight flush, {} high", name(*rank)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryInto;
+
+    use crate::card::parse_cards;
+    use crate::card::Rank;
+    use crate::three_card::ThreeCard;
+
+    fn three_card(cards: &str) -> ThreeCard {
+        ThreeCard::from_cards(parse_cards(cards).try_into().unwrap())
+    }
+
+    #[test]
+    fn test_from_cards() {
+        assert_eq!(
+            three_card("Qh6d4c"),
+            ThreeCard::HighCard {
+                ranks: [Rank::Queen, Rank::Six, Rank::Four]
+            }
+        );
+        assert_eq!(
+            three_card("9h4d9c"),
+            ThreeCard::Pair {
+                rank: Rank::Nine,
+                kicker: Rank::Four
+            }
+        );
+        assert_eq!(
+            three_card("Kh4h9h"),
+            ThreeCard::Flush {
+                ranks: [Rank::King, Rank::Nine, Rank::Four]
+            }
+        );
+        assert_eq!(
+            three_card("2hAd3c"),
+            ThreeCard::Straight { rank: Rank::Three }
+        );
+        assert_eq!(
+            three_card("QhKdAc"),
+            ThreeCard::Straight { rank: Rank::Ace }
+        );
+        assert_eq!(
+            three_card("7h7d7c"),
+            ThreeCard::ThreeOfAKind { rank: Rank::Seven }
+        );
+        assert_eq!(
+            three_card("8s9sTs"),
+            ThreeCard::StraightFlush { rank: Rank::Ten }
+        );
+    }
+
+    #[test]
+    fn test_ordering() {
+        assert!(three_card("2h3d4c") > three_card("AhKhJh"));
+        assert!(three_card("2h2d2c") > three_card("AhKdQc"));
+        assert!(three_card("AhKdQc") > three_card("2h3dAc"));
+        assert!(three_card("AhKh9h") > three_card("AhKh8h"));
+        assert!(three_card("9h9dAc") > three_card("9s9c8c"));
+        assert!(three_card("2h2dAc") < three_card("3s3c4c"));
+        assert_eq!(
+            three_card("AhKd9c").cmp(&three_card("AsKc9d")),
+            std::cmp::Ordering::Equal
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(three_card("Qh6d4c").to_string(), "high card, queen");
+        assert_eq!(three_card("6h4d6c").to_string(), "pair of sixes");
+        assert_eq!(three_card("2hAd3c").to_string(), "straight, three high");
+        assert_eq!(three_card("8s9sTs").to_string(), "straight flush, ten high");
+    }
+}
@@ -0,0 +1,422 @@
+//! Video poker machines: what a paytable pays for a final hand, the exact
+//! expected value of every hold of a deal and the return to player of a
+//! paytable under optimal play.
+//!
+//! Pays only depend on the ranks of a hand and whether its natural cards
+//! are suited, so they are worked out once per rank pattern and looked up
+//! afterwards.
+
+use std::collections::HashMap;
+use std::convert::TryInto;
+
+use ::itertools::Itertools;
+
+use crate::card::wild::WildCard;
+use crate::card::Card;
+use crate::card::Rank;
+use crate::card::Suit;
+use crate::combination::Combination;
+use crate::game::Variant;
+
+/// Paying hands, from the lowest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Win {
+    /// A pair of `Paytable::min_pair` or better.
+    HighPair,
+    TwoPairs,
+    ThreeOfAKind,
+    Straight,
+    Flush,
+    FullHouse,
+    FourOfAKind,
+    StraightFlush,
+    FiveOfAKind,
+    /// A royal flush made with wild cards.
+    WildRoyalFlush,
+    /// All four cards of a wild rank, whatever the fifth card.
+    FourWilds,
+    RoyalFlush,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Paytable {
+    /// Ranks standing for any card, none in Jacks or Better.
+    pub wild_ranks: Vec<Rank>,
+    /// The lowest pair that pays, if any pair does.
+    pub min_pair: Option<Rank>,
+    /// Credits won per credit bet; hands not listed pay nothing.
+    pub pays: Vec<(Win, u32)>,
+}
+
+impl Paytable {
+    /// Full-pay 9/6 Jacks or Better, per credit for a five-credit bet.
+    pub fn jacks_or_better() -> Self {
+        Self {
+            wild_ranks: vec![],
+            min_pair: Some(Rank::Jack),
+            pays: vec![
+                (Win::RoyalFlush, 800),
+                (Win::StraightFlush, 50),
+                (Win::FourOfAKind, 25),
+                (Win::FullHouse, 9),
+                (Win::Flush, 6),
+                (Win::Straight, 4),
+                (Win::ThreeOfAKind, 3),
+                (Win::TwoPairs, 2),
+                (Win::HighPair, 1),
+            ],
+        }
+    }
+
+    /// Full-pay Deuces Wild, per credit for a five-credit bet.
+    pub fn deuces_wild() -> Self {
+        Self {
+            wild_ranks: vec![Rank::Two],
+            min_pair: None,
+            pays: vec![
+                (Win::RoyalFlush, 800),
+                (Win::FourWilds, 200),
+                (Win::WildRoyalFlush, 25),
+                (Win::FiveOfAKind, 15),
+                (Win::StraightFlush, 9),
+                (Win::FourOfAKind, 5),
+                (Win::FullHouse, 3),
+                (Win::Flush, 2),
+                (Win::Straight, 2),
+                (Win::ThreeOfAKind, 1),
+            ],
+        }
+    }
+
+    /// The best paying hand `cards` make, if any.
+    pub fn win(&self, cards: [Card; 5]) -> Option<Win> {
+        let wilds = cards
+            .iter()
+            .filter(|card| self.wild_ranks.contains(&card.0))
+            .count();
+
+        if wilds == 4 && self.pays(Win::FourWilds) > 0 {
+            return Some(Win::FourWilds);
+        }
+
+        let combination = match wilds {
+            0 => Combination::from_variant(Variant(cards)),
+            _ => Combination::from_wild_cards(cards.map(WildCard::Natural), &self.wild_ranks),
+        };
+
+        let win = match combination {
+            Combination::FiveOfAKind { .. } => Win::FiveOfAKind,
+            Combination::StraightFlush { rank: Rank::Ten } if wilds == 0 => Win::RoyalFlush,
+            Combination::StraightFlush { rank: Rank::Ten } => Win::WildRoyalFlush,
+            Combination::StraightFlush { .. } => Win::StraightFlush,
+            Combination::FourOfAKind { .. } => Win::FourOfAKind,
+            Combination::FullHouse { .. } => Win::FullHouse,
+            Combination::Flush { .. } => Win::Flush,
+            Combination::Straight { .. } => Win::Straight,
+            Combination::ThreeOfAKind { .. } => Win::ThreeOfAKind,
+            Combination::TwoPairs { .. } => Win::TwoPairs,
+            Combination::Pair { rank, .. } if matches!(self.min_pair, Some(min) if rank >= min) => {
+                Win::HighPair
+            }
+            _ => return None,
+        };
+
+        Some(win)
+    }
+
+    pub fn pays(&self, win: Win) -> u32 {
+        self.pays
+            .iter()
+            .find(|(paying, _)| *paying == win)
+            .map_or(0, |(_, pay)| *pay)
+    }
+
+    /// Credits won per credit bet by a final hand.
+    pub fn pay(&self, cards: [Card; 5]) -> u32 {
+        self.win(cards).map_or(0, |win| self.pays(win))
+    }
+}
+
+/// Cards to keep from a deal and what they are worth.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Hold {
+    pub held: Vec<Card>,
+    /// Expected credits won per credit bet after the draw.
+    pub ev: f64,
+}
+
+/// A paytable with the pay of every rank pattern worked out.
+pub struct Machine {
+    pub paytable: Paytable,
+    pays: Vec<u32>,
+    wild: [bool; 13],
+}
+
+impl Machine {
+    pub fn new(paytable: Paytable) -> Self {
+        let mut pays = vec![0; 2 * 13usize.pow(5)];
+
+        for ranks in (0..13).combinations_with_replacement(5) {
+            for suited in [false, true] {
+                // Unsuited hands alternate suits so that their natural cards
+                // never make a flush.
+                let cards = ranks
+                    .iter()
+                    .enumerate()
+                    .map(|(i, rank)| {
+                        let suit = if suited { 0 } else { i % 4 };
+                        Card(Rank::ALL[*rank], Suit::ALL[suit])
+                    })
+                    .collect::<Vec<_>>();
+
+                pays[pattern(&ranks, suited)] = paytable.pay(cards.try_into().unwrap());
+            }
+        }
+
+        let mut wild = [false; 13];
+        for rank in &paytable.wild_ranks {
+            wild[*rank as usize] = true;
+        }
+
+        Self {
+            paytable,
+            pays,
+            wild,
+        }
+    }
+
+    /// Every hold of `deal`, best first, each valued by enumerating all the
+    /// draws from the other 47 cards.
+    pub fn holds(&self, deal: [Card; 5]) -> Vec<Hold> {
+        assert_eq!(deal.iter().unique().count(), 5, "cards are dealt twice");
+
+        let deal = deal.map(index);
+        let rest = (0..52).filter(|i| !deal.contains(i)).collect::<Vec<_>>();
+
+        (0..1 << 5)
+            .map(|mask: usize| {
+                let held = (0..5)
+                    .filter(|i| mask & (1 << i) != 0)
+                    .map(|i| deal[i])
+                    .collect::<Vec<_>>();
+
+                let (total, draws) = rest.iter().copied().combinations(5 - held.len()).fold(
+                    (0u64, 0u64),
+                    |(total, draws), drawn| {
+                        let cards = held.iter().chain(&drawn).copied().collect::<Vec<_>>();
+                        (total + self.pay(&cards) as u64, draws + 1)
+                    },
+                );
+
+                Hold {
+                    held: held.into_iter().map(card).collect(),
+                    ev: total as f64 / draws as f64,
+                }
+            })
+            .sorted_by(|a, b| b.ev.partial_cmp(&a.ev).unwrap())
+            .collect()
+    }
+
+    /// Expected credits won per credit bet when every deal is played with
+    /// its best hold.
+    ///
+    /// Summing pays over the final hands containing each set of cards,
+    /// the hands a hold can draw to are counted by inclusion-exclusion on
+    /// the discarded cards, and deals are only played once per suit pattern.
+    pub fn return_to_player(&self) -> f64 {
+        let choose = binomials();
+        // Position of the cards of `mask` among the sets of as many cards.
+        let rank = |cards: &[usize], mask: usize| {
+            (0..cards.len())
+                .filter(|i| mask & (1 << i) != 0)
+                .enumerate()
+                .fold((0, 0), |(size, rank), (k, i)| {
+                    (size + 1, rank + choose[cards[i]][k + 1])
+                })
+        };
+
+        let mut totals = (0..=5)
+            .map(|k| vec![0u64; choose[52][k]])
+            .collect::<Vec<_>>();
+        let mut classes = HashMap::<u64, (u64, [usize; 5])>::new();
+
+        for hand in (0..52).combinations(5) {
+            let pay = self.pay(&hand) as u64;
+
+            if pay > 0 {
+                for mask in 0..1 << 5 {
+                    let (size, rank) = rank(&hand, mask);
+                    totals[size][rank] += pay;
+                }
+            }
+
+            let class = classes.entry(suits(&hand)).or_insert((0, [0; 5]));
+            class.0 += 1;
+            class.1.copy_from_slice(&hand);
+        }
+
+        let returned = classes
+            .values()
+            .map(|(deals, deal)| {
+                let subsets = (0..1 << 5).map(|mask| rank(deal, mask)).collect::<Vec<_>>();
+
+                let best = (0..1 << 5)
+                    .map(|held: usize| {
+                        let discarded = !held & 0b11111;
+                        let mut total = 0i64;
+                        let mut extra = discarded;
+
+                        loop {
+                            let (size, rank) = subsets[held | extra];
+                            if extra.count_ones().is_multiple_of(2) {
+                                total += totals[size][rank] as i64;
+                            } else {
+                                total -= totals[size][rank] as i64;
+                            }
+
+                            if extra == 0 {
+                                break;
+                            }
+                            extra = (extra - 1) & discarded;
+                        }
+
+                        total as f64 / choose[47][discarded.count_ones() as usize] as f64
+                    })
+                    .fold(0.0, f64::max);
+
+                best * *deals as f64
+            })
+            .sum::<f64>();
+
+        returned / choose[52][5] as f64
+    }
+
+    fn pay(&self, cards: &[usize]) -> u32 {
+        let mut ranks = [0; 5];
+        for (rank, card) in ranks.iter_mut().zip(cards) {
+            *rank = card / 4;
+        }
+        ranks.sort_unstable();
+
+        // Wild cards take whichever suit makes a flush.
+        let mut naturals = cards.iter().filter(|card| !self.wild[*card / 4]);
+        let suited = match naturals.next() {
+            Some(first) => naturals.all(|card| card % 4 == first % 4),
+            None => true,
+        };
+
+        self.pays[pattern(&ranks, suited)]
+    }
+}
+
+/// Cards are numbered rank first, `0` for the two of diamonds.
+fn index(card: Card) -> usize {
+    card.0 as usize * 4 + card.1 as usize
+}
+
+fn card(index: usize) -> Card {
+    Card(Rank::ALL[index / 4], Suit::ALL[index % 4])
+}
+
+fn pattern(sorted_ranks: &[usize], suited: bool) -> usize {
+    let ranks = sorted_ranks.iter().fold(0, |acc, rank| acc * 13 + rank);
+
+    ranks * 2 + suited as usize
+}
+
+/// The same for deals that only differ by a relabelling of suits.
+fn suits(cards: &[usize]) -> u64 {
+    let mut masks = [0u64; 4];
+    for card in cards {
+        masks[card % 4] |= 1 << (card / 4);
+    }
+    masks.sort_unstable();
+
+    masks.iter().fold(0, |acc, mask| acc << 13 | mask)
+}
+
+/// `n` choose `k` for the deck sizes and hand sizes used here.
+fn binomials() -> Vec<[usize; 6]> {
+    let mut choose = vec![[0; 6]; 53];
+
+    for n in 0..=52 {
+        choose[n][0] = 1;
+        for k in 1..=5.min(n) {
+            choose[n][k] = choose[n - 1][k - 1] + choose[n - 1][k];
+        }
+    }
+
+    choose
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryInto;
+
+    use crate::card::parse_cards;
+    use crate::card::Card;
+    use crate::video_poker::Machine;
+    use crate::video_poker::Paytable;
+    use crate::video_poker::Win;
+
+    fn cards(s: &str) -> [Card; 5] {
+        parse_cards(s).try_into().unwrap()
+    }
+
+    #[test]
+    fn test_win_jacks_or_better() {
+        let paytable = Paytable::jacks_or_better();
+
+        assert_eq!(paytable.win(cards("JhJd7c4s2s")), Some(Win::HighPair));
+        assert_eq!(paytable.win(cards("ThTd7c4s2s")), None);
+        assert_eq!(paytable.win(cards("AhKhQhJhTh")), Some(Win::RoyalFlush));
+        assert_eq!(paytable.pay(cards("9h9d9c4s4d")), 9);
+        assert_eq!(paytable.pay(cards("AhKd7c4s2s")), 0);
+    }
+
+    #[test]
+    fn test_win_deuces_wild() {
+        let paytable = Paytable::deuces_wild();
+
+        assert_eq!(paytable.win(cards("2h2d2c2sKd")), Some(Win::FourWilds));
+        assert_eq!(paytable.win(cards("AhKhQh2dTh")), Some(Win::WildRoyalFlush));
+        assert_eq!(paytable.win(cards("AhKhQhJhTh")), Some(Win::RoyalFlush));
+        assert_eq!(paytable.win(cards("9h9d2c2s9c")), Some(Win::FiveOfAKind));
+        assert_eq!(paytable.win(cards("KhKd7c4s3s")), None);
+        assert_eq!(paytable.pay(cards("Kh2d7c4sKs")), 1);
+    }
+
+    #[test]
+    fn test_holds() {
+        let machine = Machine::new(Paytable::jacks_or_better());
+
+        let holds = machine.holds(cards("AhKhQhJhTh"));
+        assert_eq!(holds.len(), 32);
+        assert_eq!(holds[0].held, parse_cards("AhKhQhJhTh"));
+        assert_eq!(holds[0].ev, 800.0);
+
+        // Four to a royal beats the made flush.
+        let holds = machine.holds(cards("AhKhQhJh3h"));
+        assert_eq!(holds[0].held, parse_cards("AhKhQhJh"));
+
+        let flush = holds.iter().find(|hold| hold.held.len() == 5).unwrap();
+        assert_eq!(flush.ev, 6.0);
+
+        // The ten of hearts, seven flushes, three straights and twelve high pairs.
+        assert!((holds[0].ev - (800.0 + 7.0 * 6.0 + 3.0 * 4.0 + 12.0) / 47.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_return_to_player_jacks_or_better() {
+        let machine = Machine::new(Paytable::jacks_or_better());
+
+        assert!((machine.return_to_player() - 0.995439).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_return_to_player_deuces_wild() {
+        let machine = Machine::new(Paytable::deuces_wild());
+
+        assert!((machine.return_to_player() - 1.007620).abs() < 1e-6);
+    }
+}